@@ -31,9 +31,7 @@ impl RpcApi for RetryClient {
         for _ in 0..RETRY_ATTEMPTS {
             match self.client.call(cmd, args) {
                 Ok(ret) => return Ok(ret),
-                Err(Error::JsonRpc(jsonrpc::error::Error::Rpc(ref rpcerr)))
-                    if rpcerr.code == -28 =>
-                {
+                Err(ref e) if e.rpc_code() == Some(-28) => {
                     ::std::thread::sleep(::std::time::Duration::from_millis(INTERVAL));
                     continue;
                 }
@@ -0,0 +1,534 @@
+// To the extent possible under law, the author(s) have dedicated all
+// copyright and related and neighboring rights to this software to
+// the public domain worldwide. This software is distributed without
+// any warranty.
+//
+// You should have received a copy of the CC0 Public Domain Dedication
+// along with this software.
+// If not, see <http://creativecommons.org/publicdomain/zero/1.0/>.
+//
+
+//! An asynchronous client, enabled with the `async` feature.
+//!
+//! [RpcApiAsync] covers part of the API of [RpcApi](crate::RpcApi) so far: the
+//! blockchain basics, raw transactions, quorums and the provider transaction
+//! queries. Those methods take the same arguments and send the same requests as
+//! their blocking counterparts, so code can be ported by adding `.await`. The
+//! one difference is that [RpcApiAsync::call] also requires the result type to
+//! be `Send`, as the returned future has to be.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::client::{
+    loggable_args, response_bytes, Auth, MethodFilter, Observer, RawTx, Result, RpcEvent,
+};
+use crate::dashcore::{self, BlockHash, ProTxHash, QuorumHash, Transaction};
+use crate::json::{self, ProTxListType, QuorumType};
+use crate::requests;
+use crate::Error;
+
+/// Requests time out after this long unless set with
+/// [AsyncClient::new_with_timeout], the same default as for [Client](crate::Client).
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// The asynchronous counterpart of [RpcApi](crate::RpcApi).
+///
+/// Implementors only need to provide [RpcApiAsync::call], the other methods
+/// build their requests on top of it.
+#[async_trait]
+pub trait RpcApiAsync: Sized + Sync {
+    /// Call a `cmd` rpc with given `args` list
+    async fn call<T: for<'a> serde::de::Deserialize<'a> + Send>(
+        &self,
+        cmd: &str,
+        args: &[Value],
+    ) -> Result<T>;
+
+    /// Returns a data structure containing various state info regarding
+    /// blockchain processing.
+    async fn get_blockchain_info(&self) -> Result<json::GetBlockchainInfoResult> {
+        self.call("getblockchaininfo", &[]).await
+    }
+
+    /// Returns the numbers of block in the longest chain.
+    async fn get_block_count(&self) -> Result<u32> {
+        self.call("getblockcount", &[]).await
+    }
+
+    /// Returns the hash of the best (tip) block in the longest blockchain.
+    async fn get_best_block_hash(&self) -> Result<BlockHash> {
+        self.call("getbestblockhash", &[]).await
+    }
+
+    /// Get block hash at a given height
+    async fn get_block_hash(&self, height: u32) -> Result<BlockHash> {
+        self.call("getblockhash", &[height.into()]).await
+    }
+
+    async fn get_raw_transaction(
+        &self,
+        txid: &dashcore::Txid,
+        block_hash: Option<&BlockHash>,
+    ) -> Result<Transaction> {
+        let req = requests::get_raw_transaction(txid, block_hash)?;
+        let hex: String = self.call(req.cmd, &req.args).await?;
+        requests::deserialize_hex(&hex)
+    }
+
+    async fn get_raw_transaction_info(
+        &self,
+        txid: &dashcore::Txid,
+        block_hash: Option<&BlockHash>,
+    ) -> Result<json::GetRawTransactionResult> {
+        let req = requests::get_raw_transaction_info(txid, block_hash)?;
+        self.call(req.cmd, &req.args).await
+    }
+
+    async fn send_raw_transaction<R: RawTx + Send>(&self, tx: R) -> Result<dashcore::Txid> {
+        let req = requests::send_raw_transaction(tx);
+        self.call(req.cmd, &req.args).await
+    }
+
+    // -------------------------- Quorum -------------------------------
+
+    /// Returns a list of on-chain quorums
+    async fn get_quorum_list(
+        &self,
+        count: Option<u8>,
+    ) -> Result<json::QuorumListResult<Vec<QuorumHash>>> {
+        let req = requests::get_quorum_list(count)?;
+        self.call(req.cmd, &req.args).await
+    }
+
+    /// Returns an extended list of on-chain quorums
+    async fn get_quorum_listextended(
+        &self,
+        height: Option<u32>,
+    ) -> Result<json::ExtendedQuorumListResult> {
+        let req = requests::get_quorum_listextended(height)?;
+        self.call(req.cmd, &req.args).await
+    }
+
+    /// Returns information about a specific quorum
+    async fn get_quorum_info(
+        &self,
+        llmq_type: QuorumType,
+        quorum_hash: &QuorumHash,
+        include_sk_share: Option<bool>,
+    ) -> Result<json::QuorumInfoResult> {
+        let req = requests::get_quorum_info(llmq_type, quorum_hash, include_sk_share)?;
+        self.call(req.cmd, &req.args).await
+    }
+
+    /// Returns the status of the current DKG process
+    async fn get_quorum_dkgstatus(
+        &self,
+        detail_level: Option<u8>,
+    ) -> Result<json::QuorumDKGStatus> {
+        let req = requests::get_quorum_dkgstatus(detail_level)?;
+        self.call(req.cmd, &req.args).await
+    }
+
+    /// Requests threshold-signing for a message
+    async fn get_quorum_sign(
+        &self,
+        llmq_type: QuorumType,
+        id: &str,
+        msg_hash: &str,
+        quorum_hash: Option<&str>,
+        submit: Option<bool>,
+    ) -> Result<json::QuorumSignResult> {
+        let req = requests::get_quorum_sign(llmq_type, id, msg_hash, quorum_hash, submit)?;
+        self.call(req.cmd, &req.args).await
+    }
+
+    /// Returns the recovered signature for a previous threshold-signing message request
+    async fn get_quorum_getrecsig(
+        &self,
+        llmq_type: QuorumType,
+        id: &str,
+        msg_hash: &str,
+    ) -> Result<json::QuorumSignature> {
+        let req = requests::get_quorum_recsig("getrecsig", llmq_type, id, msg_hash)?;
+        self.call(req.cmd, &req.args).await
+    }
+
+    /// Checks for a recovered signature for a previous threshold-signing message request
+    async fn get_quorum_hasrecsig(
+        &self,
+        llmq_type: QuorumType,
+        id: &str,
+        msg_hash: &str,
+    ) -> Result<bool> {
+        let req = requests::get_quorum_recsig("hasrecsig", llmq_type, id, msg_hash)?;
+        self.call(req.cmd, &req.args).await
+    }
+
+    /// Checks if there is a conflict for a threshold-signing message request
+    async fn get_quorum_isconflicting(
+        &self,
+        llmq_type: QuorumType,
+        id: &str,
+        msg_hash: &str,
+    ) -> Result<bool> {
+        let req = requests::get_quorum_recsig("isconflicting", llmq_type, id, msg_hash)?;
+        self.call(req.cmd, &req.args).await
+    }
+
+    /// Checks which quorums the given masternode is a member of
+    async fn get_quorum_memberof(
+        &self,
+        pro_tx_hash: &ProTxHash,
+        scan_quorums_count: Option<u8>,
+    ) -> Result<json::QuorumMemberOfResult> {
+        let req = requests::get_quorum_memberof(pro_tx_hash, scan_quorums_count)?;
+        self.call(req.cmd, &req.args).await
+    }
+
+    /// Returns quorum rotation information
+    async fn get_quorum_rotationinfo(
+        &self,
+        block_request_hash: &BlockHash,
+        extra_share: Option<bool>,
+        base_block_hash: Option<&str>,
+    ) -> Result<json::QuorumRotationInfo> {
+        let req =
+            requests::get_quorum_rotationinfo(block_request_hash, extra_share, base_block_hash)?;
+        self.call(req.cmd, &req.args).await
+    }
+
+    /// Returns information about the quorum that would/should sign a request
+    async fn get_quorum_selectquorum(
+        &self,
+        llmq_type: QuorumType,
+        id: &str,
+    ) -> Result<json::SelectQuorumResult> {
+        let req = requests::get_quorum_selectquorum(llmq_type, id)?;
+        self.call(req.cmd, &req.args).await
+    }
+
+    /// Tests if a quorum signature is valid for a request id and a message hash
+    async fn get_quorum_verify(
+        &self,
+        llmq_type: QuorumType,
+        id: &str,
+        msg_hash: &str,
+        signature: &str,
+        quorum_hash: Option<QuorumHash>,
+        sign_height: Option<u32>,
+    ) -> Result<bool> {
+        let req = requests::get_quorum_verify(
+            llmq_type,
+            id,
+            msg_hash,
+            signature,
+            quorum_hash,
+            sign_height,
+        )?;
+        self.call(req.cmd, &req.args).await
+    }
+
+    // --------------------------- ProTx -------------------------------
+
+    /// Returns a diff and a proof between two masternode list
+    async fn get_protx_diff(&self, base_block: u32, block: u32) -> Result<json::MasternodeDiff> {
+        let req = requests::get_protx_diff(base_block, block)?;
+        self.call(req.cmd, &req.args).await
+    }
+
+    /// Returns a full deterministic masternode list diff between two heigts
+    async fn get_protx_listdiff(
+        &self,
+        base_block: u32,
+        block: u32,
+    ) -> Result<json::MasternodeListDiff> {
+        let req = requests::get_protx_listdiff(base_block, block)?;
+        self.call(req.cmd, &req.args).await
+    }
+
+    /// Returns a returns detailed information about a deterministic masternode
+    async fn get_protx_info(
+        &self,
+        protx_hash: &ProTxHash,
+        block_hash: Option<&BlockHash>,
+    ) -> Result<json::ProTxInfo> {
+        let req = requests::get_protx_info(protx_hash, block_hash)?;
+        self.call(req.cmd, &req.args).await
+    }
+
+    /// Returns a list of provider transactions
+    async fn get_protx_list(
+        &self,
+        protx_type: Option<ProTxListType>,
+        detailed: Option<bool>,
+        height: Option<u32>,
+    ) -> Result<json::ProTxList> {
+        let req = requests::get_protx_list(protx_type, detailed, height)?;
+        requests::protx_list_result(detailed, self.call(req.cmd, &req.args).await?)
+    }
+}
+
+/// Client implements an asynchronous JSON-RPC client for the Dash Core daemon
+/// or compatible APIs, sending its requests over HTTP with `reqwest`.
+///
+/// Unlike [Client](crate::Client), requests don't wait for each other, so many
+/// calls can be in flight at once.
+pub struct AsyncClient {
+    http: reqwest::Client,
+    url: String,
+    user: Option<String>,
+    pass: Option<String>,
+    nonce: AtomicUsize,
+    methods: MethodFilter,
+    observer: Option<Observer>,
+}
+
+impl fmt::Debug for AsyncClient {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "dashcore_rpc::AsyncClient({})", self.url)
+    }
+}
+
+impl AsyncClient {
+    /// Creates a client to a dashd JSON-RPC server.
+    ///
+    /// Requests time out after 15 seconds like those of [Client](crate::Client),
+    /// see [AsyncClient::new_with_timeout] to change it.
+    pub fn new(url: &str, auth: Auth) -> Result<Self> {
+        AsyncClient::new_with_timeout(url, auth, DEFAULT_TIMEOUT)
+    }
+
+    /// Creates a client to a dashd JSON-RPC server whose requests time out
+    /// after `timeout`.
+    pub fn new_with_timeout(url: &str, auth: Auth, timeout: Duration) -> Result<Self> {
+        let (user, pass) = auth.get_user_pass()?;
+        Ok(AsyncClient {
+            http: reqwest::Client::builder().timeout(timeout).build().map_err(transport_error)?,
+            url: url.to_owned(),
+            user,
+            pass,
+            nonce: AtomicUsize::new(1),
+            methods: MethodFilter::default(),
+            observer: None,
+        })
+    }
+
+    /// Call `observer` with an [RpcEvent] after every RPC call that was sent,
+    /// like [Client::with_observer](crate::Client::with_observer).
+    pub fn with_observer(
+        mut self,
+        observer: impl Fn(&RpcEvent) + Send + Sync + 'static,
+    ) -> AsyncClient {
+        self.observer = Some(Box::new(observer));
+        self
+    }
+
+    /// Only allow the given RPC commands to be sent, like
+    /// [Client::with_allowed_methods](crate::Client::with_allowed_methods).
+    pub fn with_allowed_methods(mut self, allow: HashSet<String>) -> AsyncClient {
+        self.methods.allowed = Some(allow);
+        self
+    }
+
+    /// Forbid the given RPC commands from being sent, like
+    /// [Client::with_denied_methods](crate::Client::with_denied_methods).
+    pub fn with_denied_methods(mut self, deny: HashSet<String>) -> AsyncClient {
+        self.methods.denied = deny;
+        self
+    }
+
+    async fn send(&self, request: &Value) -> Result<jsonrpc::Response> {
+        let mut builder = self.http.post(&self.url).json(request);
+        if let Some(ref user) = self.user {
+            builder = builder.basic_auth(user, self.pass.as_ref());
+        }
+        let response = builder.send().await.map_err(transport_error)?;
+        let status = response.status();
+        let body = response.bytes().await.map_err(transport_error)?;
+        // Core answers errors with a non-200 status but still a JSON-RPC body,
+        // so the status only matters when there is none, as for a 401.
+        match serde_json::from_slice(&body) {
+            Ok(response) => Ok(response),
+            Err(_) if !status.is_success() => {
+                let e = jsonrpc::simple_http::Error::HttpErrorCode(status.as_u16());
+                Err(Error::JsonRpc(jsonrpc::Error::Transport(Box::new(e))))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[async_trait]
+impl RpcApiAsync for AsyncClient {
+    /// Call an `cmd` rpc with given `args` list
+    async fn call<T: for<'a> serde::de::Deserialize<'a> + Send>(
+        &self,
+        cmd: &str,
+        args: &[Value],
+    ) -> Result<T> {
+        self.methods.check(cmd)?;
+        let request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": self.nonce.fetch_add(1, Ordering::Relaxed),
+            "method": cmd,
+            "params": args,
+        });
+        if log_enabled!(log::Level::Debug) {
+            debug!(target: "dashcore_rpc", "JSON-RPC request: {} {}", cmd, loggable_args(cmd, args));
+        }
+
+        let start = Instant::now();
+        let resp = self.send(&request).await;
+        if let Ok(jsonrpc::Response {
+            error: Some(ref e),
+            ..
+        }) = resp
+        {
+            debug!(target: "dashcore_rpc", "JSON-RPC error for {}: {:?}", cmd, e);
+        }
+        let response_bytes = response_bytes(&resp);
+        let result = resp.and_then(|resp| Ok(resp.result()?));
+
+        if let Some(ref observer) = self.observer {
+            observer(&RpcEvent {
+                method: cmd,
+                duration: start.elapsed(),
+                error: result.as_ref().err(),
+                request_bytes: args.iter().map(|a| a.to_string().len()).sum(),
+                response_bytes,
+            });
+        }
+        result
+    }
+}
+
+fn transport_error(e: reqwest::Error) -> Error {
+    Error::JsonRpc(jsonrpc::Error::Transport(Box::new(e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dashcore::hashes::hex::FromHex;
+
+    /// Answers the first request made to the returned URL with an HTTP
+    /// `status` and `body`; the task returns the raw request it received.
+    async fn serve_once(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, tokio::task::JoinHandle<String>) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            loop {
+                let n = socket.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
+                let text = String::from_utf8_lossy(&request).into_owned();
+                if let Some(end) = text.find("\r\n\r\n") {
+                    let length = text[..end]
+                        .lines()
+                        .filter_map(|line| line.split_once(':'))
+                        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                        .map_or(0, |(_, value)| value.trim().parse().unwrap());
+                    if request.len() >= end + 4 + length {
+                        break;
+                    }
+                }
+                if n == 0 {
+                    break;
+                }
+            }
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+            String::from_utf8(request).unwrap()
+        });
+        (url, server)
+    }
+
+    #[tokio::test]
+    async fn test_result() {
+        let (url, server) = serve_once("200 OK", r#"{"result":1234,"error":null,"id":1}"#).await;
+        let auth = Auth::UserPass("user".into(), "pass".into());
+        let client = AsyncClient::new(&url, auth).unwrap();
+        assert_eq!(1234, client.get_block_count().await.unwrap());
+
+        let request = server.await.unwrap();
+        assert!(request.starts_with("POST / "), "{}", request);
+        // "user:pass" in base64.
+        assert!(request.contains("dXNlcjpwYXNz"), "{}", request);
+        assert!(request.to_lowercase().contains("authorization: basic "), "{}", request);
+        assert!(request.contains(r#""method":"getblockcount""#), "{}", request);
+    }
+
+    #[tokio::test]
+    async fn test_rpc_error() {
+        let body = r#"{"result":null,"error":{"code":-5,"message":"Block not found"},"id":1}"#;
+        let (url, server) = serve_once("500 Internal Server Error", body).await;
+        let client = AsyncClient::new(&url, Auth::None).unwrap();
+        match client.get_block_hash(1_000_000).await {
+            Err(Error::RpcError {
+                code: -5,
+                ..
+            }) => {}
+            other => panic!("expected RPC error -5, got {:?}", other),
+        }
+        let request = server.await.unwrap();
+        assert!(!request.to_lowercase().contains("authorization:"), "{}", request);
+    }
+
+    #[tokio::test]
+    async fn test_http_error() {
+        let (url, server) = serve_once("401 Unauthorized", "").await;
+        let client = AsyncClient::new(&url, Auth::None).unwrap();
+        let err = client.get_block_count().await.unwrap_err();
+        assert!(matches!(err, Error::JsonRpc(_)), "{:?}", err);
+        assert!(err.to_string().contains("401"), "{}", err);
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_unreachable() {
+        let client = AsyncClient::new("http://localhost:1/", Auth::None).unwrap();
+        assert!(matches!(client.get_block_count().await, Err(Error::JsonRpc(_))));
+        let hash = QuorumHash::from_hex(
+            "000000000c9eddd5d2a707281b7e30d5aac974dac600ff10f01937e1ca36066f",
+        )
+        .unwrap();
+        assert!(client.get_quorum_info(QuorumType::LlmqTest, &hash, None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_method_filter_and_observer() {
+        use std::sync::{Arc, Mutex};
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let observed = events.clone();
+        // Nothing can listen on port 0, so the request fails right away.
+        let client = AsyncClient::new("http://127.0.0.1:0/", Auth::None)
+            .unwrap()
+            .with_denied_methods(["getblockhash".to_string()].into_iter().collect())
+            .with_observer(move |event| {
+                observed.lock().unwrap().push((event.method.to_string(), event.error.is_some()));
+            });
+
+        assert!(matches!(client.get_block_hash(1).await, Err(Error::MethodNotAllowed(_))));
+        assert!(client.get_block_count().await.is_err());
+        assert_eq!(*events.lock().unwrap(), vec![("getblockcount".to_string(), true)]);
+    }
+}
@@ -8,12 +8,14 @@
 // If not, see <http://creativecommons.org/publicdomain/zero/1.0/>.
 //
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs::File;
 use std::iter::FromIterator;
+use std::marker::PhantomData;
 use std::path::PathBuf;
 use std::str::FromStr;
-use std::{fmt, result};
+use std::time::{Duration, Instant};
+use std::{fmt, result, thread};
 
 use crate::dashcore;
 use jsonrpc;
@@ -23,10 +25,14 @@ use serde_json::{self, Value};
 use crate::dashcore::address::NetworkUnchecked;
 use crate::dashcore::{block, consensus, ScriptBuf};
 use dashcore::hashes::hex::FromHex;
+use dashcore::hashes::sha256d;
 use dashcore::secp256k1::ecdsa::Signature;
 use dashcore::{
-    Address, Amount, Block, OutPoint, PrivateKey, ProTxHash, PublicKey, QuorumHash, Transaction,
+    Address, Amount, Block, Network, OutPoint, PrivateKey, ProTxHash, PublicKey, QuorumHash,
+    Transaction,
 };
+#[cfg(feature = "bls")]
+use dashcore_rpc_json::dashcore::bls_sig_utils::BLSPublicKey;
 use dashcore_rpc_json::dashcore::bls_sig_utils::BLSSignature;
 use dashcore_rpc_json::dashcore::{BlockHash, ChainLock};
 use dashcore_rpc_json::{ProTxInfo, ProTxListType, QuorumType};
@@ -36,6 +42,7 @@ use crate::dashcore::secp256k1::hashes::hex::DisplayHex;
 use crate::error::*;
 use crate::json;
 use crate::queryable;
+use crate::requests;
 use crate::Error::UnexpectedStructure;
 
 /// Crate-specific Result type, shorthand for `std::result::Result` with our
@@ -66,8 +73,12 @@ impl Into<OutPoint> for JsonOutPoint {
     }
 }
 
+/// A bare DASH amount returned by an RPC, parsed without going through `f64`.
+#[derive(Deserialize)]
+struct JsonAmount(#[serde(with = "json::serde_amount::as_btc")] Amount);
+
 /// Shorthand for converting a variable into a serde_json::Value.
-fn into_json<T>(val: T) -> Result<Value>
+pub(crate) fn into_json<T>(val: T) -> Result<Value>
 where
     T: serde::ser::Serialize,
 {
@@ -75,7 +86,7 @@ where
 }
 
 /// Shorthand for converting an Option into an Option<serde_json::Value>.
-fn opt_into_json<T>(opt: Option<T>) -> Result<Value>
+pub(crate) fn opt_into_json<T>(opt: Option<T>) -> Result<Value>
 where
     T: serde::ser::Serialize,
 {
@@ -86,7 +97,7 @@ where
 }
 
 /// Shorthand for `serde_json::Value::Null`.
-fn null() -> Value {
+pub(crate) fn null() -> Value {
     Value::Null
 }
 
@@ -115,7 +126,7 @@ fn empty_obj() -> Value {
 ///
 /// Elements of `args` without corresponding `defaults` value, won't
 /// be substituted, because they are required.
-fn handle_defaults<'a, 'b>(args: &'a mut [Value], defaults: &'b [Value]) -> &'a [Value] {
+pub(crate) fn handle_defaults<'a, 'b>(args: &'a mut [Value], defaults: &'b [Value]) -> &'a [Value] {
     assert!(args.len() >= defaults.len());
 
     // Pass over the optional arguments in backwards order, filling in defaults after the first
@@ -154,6 +165,24 @@ fn opt_result<T: for<'a> serde::de::Deserialize<'a>>(result: Value) -> Result<Op
     }
 }
 
+/// Parse an outpoint from its txid and output index strings.
+fn parse_outpoint(txid: &str, vout: &str) -> Result<OutPoint> {
+    Ok(OutPoint {
+        txid: dashcore::Txid::from_str(txid)
+            .map_err(|e| UnexpectedStructure(format!("invalid txid {}: {}", txid, e)))?,
+        vout: vout
+            .parse()
+            .map_err(|e| UnexpectedStructure(format!("invalid output index {}: {}", vout, e)))?,
+    })
+}
+
+/// Check that an address given by core belongs to `network`.
+fn require_network(address: Address<NetworkUnchecked>, network: Network) -> Result<Address> {
+    address.require_network(network).map_err(|e| {
+        UnexpectedStructure(format!("address given by core is not valid for {:?}: {}", network, e))
+    })
+}
+
 /// Used to pass raw txs into the API.
 pub trait RawTx: Sized + Clone {
     fn raw_hex(self) -> String;
@@ -218,10 +247,65 @@ impl Auth {
     }
 }
 
+/// Iterator over the pages of an RPC paginated by trailing count/skip arguments.
+///
+/// Created by [`RpcApi::paginate`]. Every call to `next` issues one request and
+/// yields one page; iteration ends after a short or empty page, or an error.
+pub struct Paginate<'a, R, T> {
+    rpc: &'a R,
+    cmd: String,
+    base_args: Vec<Value>,
+    page_size: usize,
+    skip: usize,
+    done: bool,
+    _item: PhantomData<T>,
+}
+
+impl<'a, R: RpcApi, T: for<'de> serde::de::Deserialize<'de>> Iterator for Paginate<'a, R, T> {
+    type Item = Result<Vec<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut args = self.base_args.clone();
+        args.push(self.page_size.into());
+        args.push(self.skip.into());
+        let page = self.rpc.call::<Vec<T>>(&self.cmd, &args);
+        match page {
+            Ok(ref items) => {
+                self.skip += items.len();
+                self.done = items.is_empty() || items.len() < self.page_size;
+            }
+            Err(_) => self.done = true,
+        }
+        Some(page)
+    }
+}
+
 pub trait RpcApi: Sized {
     /// Call a `cmd` rpc with given `args` list
     fn call<T: for<'a> serde::de::Deserialize<'a>>(&self, cmd: &str, args: &[Value]) -> Result<T>;
 
+    /// Page through a `cmd` rpc whose last two arguments are count and skip,
+    /// `page_size` items at a time. `base_args` are the arguments before count.
+    fn paginate<T: for<'a> serde::de::Deserialize<'a>>(
+        &self,
+        cmd: &str,
+        base_args: &[Value],
+        page_size: usize,
+    ) -> Paginate<'_, Self, T> {
+        Paginate {
+            rpc: self,
+            cmd: cmd.to_owned(),
+            base_args: base_args.to_vec(),
+            page_size,
+            skip: 0,
+            done: false,
+            _item: PhantomData,
+        }
+    }
+
     /// Query an object implementing `Querable` type
     fn get_by_id<T: queryable::Queryable<Self>>(
         &self,
@@ -259,6 +343,19 @@ pub trait RpcApi: Sized {
         self.call("addmultisigaddress", handle_defaults(&mut args, &[into_json("")?, null()]))
     }
 
+    /// Create a multisig address from `keys` without adding it to a wallet,
+    /// so it also works on a node with no wallet loaded.
+    fn create_multisig(
+        &self,
+        nrequired: usize,
+        keys: &[PublicKey],
+        address_type: Option<json::AddressType>,
+    ) -> Result<json::CreateMultisigResult> {
+        let keys: Vec<String> = keys.iter().map(|k| k.to_string()).collect();
+        let mut args = [into_json(nrequired)?, into_json(keys)?, opt_into_json(address_type)?];
+        self.call("createmultisig", handle_defaults(&mut args, &[null()]))
+    }
+
     fn load_wallet(&self, wallet: &str) -> Result<json::LoadWalletResult> {
         self.call("loadwallet", &[wallet.into()])
     }
@@ -293,6 +390,21 @@ pub trait RpcApi: Sized {
         self.call("listwallets", &[])
     }
 
+    /// Lists the names of the wallets in the node's wallet directory, whether
+    /// they are loaded or not.
+    fn list_wallet_dir(&self) -> Result<Vec<String>> {
+        #[derive(Deserialize)]
+        struct Name {
+            name: String,
+        }
+        #[derive(Deserialize)]
+        struct Response {
+            wallets: Vec<Name>,
+        }
+        let res: Response = self.call("listwalletdir", &[])?;
+        Ok(res.wallets.into_iter().map(|w| w.name).collect())
+    }
+
     fn get_wallet_info(&self) -> Result<json::GetWalletInfoResult> {
         self.call("getwalletinfo", &[])
     }
@@ -310,6 +422,29 @@ pub trait RpcApi: Sized {
         self.call("encryptwallet", &[into_json(passphrase)?])
     }
 
+    /// Sets the HD seed of a non-HD wallet to `seed`, or to a fresh random
+    /// seed if `None`. Unless `new_keypool` is false, the keypool is flushed and
+    /// refilled from the new seed.
+    fn set_hd_seed(&self, new_keypool: Option<bool>, seed: Option<&PrivateKey>) -> Result<()> {
+        let mut args = [opt_into_json(new_keypool)?, opt_into_json(seed.map(|s| s.to_wif()))?];
+        self.call("sethdseed", handle_defaults(&mut args, &[true.into(), null()]))
+    }
+
+    /// Unlocks an encrypted wallet for `timeout` seconds.
+    fn wallet_passphrase(&self, passphrase: &str, timeout: u64) -> Result<()> {
+        self.call("walletpassphrase", &[into_json(passphrase)?, into_json(timeout)?])
+    }
+
+    /// Locks an encrypted wallet again, before its unlock timeout expires.
+    fn wallet_lock(&self) -> Result<()> {
+        self.call("walletlock", &[])
+    }
+
+    /// Changes the passphrase of an encrypted wallet from `old` to `new`.
+    fn wallet_passphrase_change(&self, old: &str, new: &str) -> Result<()> {
+        self.call("walletpassphrasechange", &[into_json(old)?, into_json(new)?])
+    }
+
     fn get_difficulty(&self) -> Result<f64> {
         self.call("getdifficulty", &[])
     }
@@ -335,6 +470,17 @@ pub trait RpcApi: Sized {
     fn get_block_info(&self, hash: &BlockHash) -> Result<json::GetBlockResult> {
         self.call("getblock", &[into_json(hash)?, 1.into()])
     }
+
+    /// Returns the block together with its height, confirmations and chainlock status.
+    ///
+    /// The block and its context are fetched with two separate requests, so the
+    /// context may already be newer than the moment the block was read. [Client]
+    /// sends both in a single batch instead.
+    fn get_block_with_context(&self, hash: &BlockHash) -> Result<(Block, json::BlockContext)> {
+        let block = self.get_block(hash)?;
+        let info = self.get_block_info(hash)?;
+        Ok((block, json::BlockContext::from(&info)))
+    }
     //TODO(stevenroose) add getblock_txs
 
     fn get_block_header(&self, hash: &BlockHash) -> Result<block::Header> {
@@ -347,6 +493,29 @@ pub trait RpcApi: Sized {
         self.call("getblockheader", &[into_json(hash)?, true.into()])
     }
 
+    /// Returns up to `count` headers starting at `hash`. Core rejects a
+    /// `count` above 2000.
+    fn get_block_headers(
+        &self,
+        hash: &BlockHash,
+        count: u32,
+    ) -> Result<Vec<json::GetBlockHeaderResult>> {
+        self.call("getblockheaders", &[into_json(hash)?, into_json(count)?, true.into()])
+    }
+
+    /// Like [RpcApi::get_block_headers], but returns the deserialized headers.
+    fn get_block_headers_hex(&self, hash: &BlockHash, count: u32) -> Result<Vec<block::Header>> {
+        let hexes: Vec<String> =
+            self.call("getblockheaders", &[into_json(hash)?, into_json(count)?, false.into()])?;
+        hexes
+            .iter()
+            .map(|hex| {
+                let bytes: Vec<u8> = FromHex::from_hex(hex)?;
+                Ok(dashcore::consensus::encode::deserialize(&bytes)?)
+            })
+            .collect()
+    }
+
     fn get_mining_info(&self) -> Result<json::GetMiningInfoResult> {
         self.call("getmininginfo", &[])
     }
@@ -374,12 +543,66 @@ pub trait RpcApi: Sized {
         )
     }
 
+    /// Returns whether `template` was built on a block that is no longer the
+    /// chain tip, so work on it would be wasted.
+    fn is_block_template_stale(&self, template: &json::GetBlockTemplateResult) -> Result<bool> {
+        Ok(template.previous_block_hash != self.get_best_block_hash()?)
+    }
+
+    /// Submits a solved block. If the node rejects it, fails with its reason.
+    fn submit_block(&self, block: &Block) -> Result<()> {
+        self.submit_block_hex(&consensus::encode::serialize(block).to_lower_hex_string())
+    }
+
+    /// Like [RpcApi::submit_block], for a hex-encoded block.
+    fn submit_block_hex(&self, hex: &str) -> Result<()> {
+        match self.call::<Option<String>>("submitblock", &[into_json(hex)?])? {
+            None => Ok(()),
+            Some(reason) => Err(UnexpectedStructure(reason)),
+        }
+    }
+
     /// Returns a data structure containing various state info regarding
     /// blockchain processing.
     fn get_blockchain_info(&self) -> Result<json::GetBlockchainInfoResult> {
         self.call("getblockchaininfo", &[])
     }
 
+    /// Returns whether the softfork `name` is active at the tip, `false` if the
+    /// node does not know it.
+    fn is_softfork_active(&self, name: &str) -> Result<bool> {
+        let info = self.get_blockchain_info()?;
+        Ok(info.softforks.get(name).map_or(false, |softfork| softfork.active))
+    }
+
+    /// Returns the network the node is running on, based on the `chain` reported
+    /// by `getblockchaininfo`.
+    fn network(&self) -> Result<Network> {
+        let chain = self.get_blockchain_info()?.chain;
+        match chain.as_str() {
+            "main" => Ok(Network::Dash),
+            "test" => Ok(Network::Testnet),
+            "regtest" => Ok(Network::Regtest),
+            c if c.starts_with("devnet") => Ok(Network::Devnet),
+            _ => Err(UnexpectedStructure(format!("unknown chain: {}", chain))),
+        }
+    }
+
+    /// Polls `getblockchaininfo` every `poll` until the node has left initial
+    /// block download and its verification progress is (nearly) complete.
+    ///
+    /// `progress_cb` is invoked with the verification progress after every poll.
+    fn wait_until_synced(&self, poll: Duration, mut progress_cb: impl FnMut(f64)) -> Result<()> {
+        loop {
+            let info = self.get_blockchain_info()?;
+            progress_cb(info.verification_progress);
+            if !info.initial_block_download && info.verification_progress >= 0.9999 {
+                return Ok(());
+            }
+            thread::sleep(poll);
+        }
+    }
+
     /// Returns the numbers of block in the longest chain.
     fn get_block_count(&self) -> Result<u32> {
         self.call("getblockcount", &[])
@@ -407,6 +630,19 @@ pub trait RpcApi: Sized {
         })
     }
 
+    /// Returns whether the current tip is covered by the best known chainlock.
+    ///
+    /// The best chainlock can lag behind the tip, or point at a block this node
+    /// doesn't know yet, in which case the tip is not considered chainlocked.
+    fn is_tip_chainlocked(&self) -> Result<bool> {
+        let chain_lock: json::GetBestChainLockResult = self.call("getbestchainlock", &[])?;
+        if !chain_lock.known_block {
+            return Ok(false);
+        }
+        let best_block_hash = self.get_best_block_hash()?;
+        Ok(chain_lock.blockhash == best_block_hash)
+    }
+
     /// Get block hash at a given height
     fn get_block_hash(&self, height: u32) -> Result<BlockHash> {
         self.call("getblockhash", &[height.into()])
@@ -435,15 +671,20 @@ pub trait RpcApi: Sized {
         Ok(address)
     }
 
+    /// Like [RpcApi::get_raw_change_address], but checks the address against the
+    /// node's [RpcApi::network].
+    fn get_raw_change_address_checked(&self) -> Result<Address> {
+        require_network(self.get_raw_change_address()?, self.network()?)
+    }
+
     fn get_raw_transaction(
         &self,
         txid: &dashcore::Txid,
         block_hash: Option<&BlockHash>,
     ) -> Result<Transaction> {
-        let mut args = [into_json(txid)?, into_json(false)?, opt_into_json(block_hash)?];
-        let hex: String = self.call("getrawtransaction", handle_defaults(&mut args, &[null()]))?;
-        let bytes: Vec<u8> = FromHex::from_hex(&hex)?;
-        Ok(dashcore::consensus::encode::deserialize(&bytes)?)
+        let req = requests::get_raw_transaction(txid, block_hash)?;
+        let hex: String = self.call(req.cmd, &req.args)?;
+        requests::deserialize_hex(&hex)
     }
 
     fn get_instant_locks(
@@ -481,8 +722,51 @@ pub trait RpcApi: Sized {
         txid: &dashcore::Txid,
         block_hash: Option<&BlockHash>,
     ) -> Result<json::GetRawTransactionResult> {
-        let mut args = [into_json(txid)?, into_json(true)?, opt_into_json(block_hash)?];
-        self.call("getrawtransaction", handle_defaults(&mut args, &[null()]))
+        let req = requests::get_raw_transaction_info(txid, block_hash)?;
+        self.call(req.cmd, &req.args)
+    }
+
+    /// Returns up to `count` (10 by default) special transactions of the block,
+    /// after skipping `skip`, optionally only those of `tx_type`.
+    fn get_special_txes(
+        &self,
+        block_hash: &BlockHash,
+        tx_type: Option<i32>,
+        count: Option<u32>,
+        skip: Option<u32>,
+        verbosity: Option<u32>,
+    ) -> Result<json::GetSpecialTxesResult> {
+        let mut args = [
+            into_json(block_hash)?,
+            opt_into_json(tx_type)?,
+            opt_into_json(count)?,
+            opt_into_json(skip)?,
+            opt_into_json(verbosity)?,
+        ];
+        let defaults = [into_json(-1)?, 10.into(), 0.into(), 0.into()];
+        let result = self.call("getspecialtxes", handle_defaults(&mut args, &defaults))?;
+        special_txes_result(verbosity, result)
+    }
+
+    /// Polls the transaction every `poll` until it is InstantSend locked.
+    ///
+    /// Returns `false` if the lock did not appear within `timeout`.
+    fn wait_for_instantlock(
+        &self,
+        txid: &dashcore::Txid,
+        timeout: Duration,
+        poll: Duration,
+    ) -> Result<bool> {
+        let start = Instant::now();
+        loop {
+            if self.get_raw_transaction_info(txid, None)?.instantlock {
+                return Ok(true);
+            }
+            if start.elapsed() >= timeout {
+                return Ok(false);
+            }
+            thread::sleep(poll);
+        }
     }
 
     fn get_block_filter(&self, block_hash: &BlockHash) -> Result<json::GetBlockFilterResult> {
@@ -495,20 +779,27 @@ pub trait RpcApi: Sized {
         include_watchonly: Option<bool>,
     ) -> Result<Amount> {
         let mut args = ["*".into(), opt_into_json(minconf)?, opt_into_json(include_watchonly)?];
-        Ok(Amount::from_btc(
-            self.call("getbalance", handle_defaults(&mut args, &[0.into(), null()]))?,
-        )?)
+        let amount: JsonAmount =
+            self.call("getbalance", handle_defaults(&mut args, &[0.into(), null()]))?;
+        Ok(amount.0)
     }
 
     fn get_balances(&self) -> Result<json::GetBalancesResult> {
         Ok(self.call("getbalances", &[])?)
     }
 
-    fn get_received_by_address(&self, address: &Address, minconf: Option<u32>) -> Result<Amount> {
-        let mut args = [address.to_string().into(), opt_into_json(minconf)?];
-        Ok(Amount::from_btc(
-            self.call("getreceivedbyaddress", handle_defaults(&mut args, &[null()]))?,
-        )?)
+    /// `add_locked` also counts InstantSend locked transactions as confirmed.
+    fn get_received_by_address(
+        &self,
+        address: &Address,
+        minconf: Option<u32>,
+        add_locked: Option<bool>,
+    ) -> Result<Amount> {
+        let mut args =
+            [address.to_string().into(), opt_into_json(minconf)?, opt_into_json(add_locked)?];
+        let amount: JsonAmount =
+            self.call("getreceivedbyaddress", handle_defaults(&mut args, &[1.into(), null()]))?;
+        Ok(amount.0)
     }
 
     fn get_transaction_are_locked(
@@ -537,6 +828,47 @@ pub trait RpcApi: Sized {
         self.call("getassetunlockstatuses", &args)
     }
 
+    /// Polls the status of the asset unlock with the given `index` every `poll`
+    /// until it reaches `target` or a later status, and returns that status.
+    ///
+    /// Fails with [Error::Timeout] if the status is not reached within `timeout`.
+    fn wait_for_asset_unlock(
+        &self,
+        index: u64,
+        target: json::AssetUnlockStatus,
+        timeout: Duration,
+        poll: Duration,
+    ) -> Result<json::AssetUnlockStatusResult> {
+        let start = Instant::now();
+        loop {
+            let status = self
+                .get_asset_unlock_statuses(&[index], None)?
+                .into_iter()
+                .find(|status| status.index == index)
+                .ok_or_else(|| {
+                    UnexpectedStructure(format!("no status returned for asset unlock {}", index))
+                })?;
+            if status.status >= target {
+                return Ok(status);
+            }
+            if start.elapsed() >= timeout {
+                return Err(Error::Timeout(format!("asset unlock {} to be {:?}", index, target)));
+            }
+            thread::sleep(poll);
+        }
+    }
+
+    /// Returns the wallet's view of a transaction, also counting watch-only
+    /// addresses in the amounts and details if `include_watchonly` is set
+    fn get_transaction(
+        &self,
+        txid: &dashcore::Txid,
+        include_watchonly: Option<bool>,
+    ) -> Result<json::GetTransactionResult> {
+        let mut args = [into_json(txid)?, opt_into_json(include_watchonly)?];
+        self.call("gettransaction", handle_defaults(&mut args, &[null()]))
+    }
+
     fn list_transactions(
         &self,
         label: Option<&str>,
@@ -589,6 +921,34 @@ pub trait RpcApi: Sized {
         Ok(FromHex::from_hex(&hex)?)
     }
 
+    /// Verifies a proof made by [RpcApi::get_tx_out_proof] and returns the
+    /// txids it commits to, or none if its block is not in the best chain.
+    fn verify_tx_out_proof(&self, proof: &[u8]) -> Result<Vec<dashcore::Txid>> {
+        self.call("verifytxoutproof", &[into_json(proof.to_lower_hex_string())?])
+    }
+
+    /// Returns hex-encoded merkleblock messages for up to `count` blocks
+    /// starting at `hash`, filtered by the hex-encoded bloom `filter`.
+    fn get_merkle_blocks(&self, filter: &str, hash: &BlockHash, count: u32) -> Result<Vec<String>> {
+        self.call("getmerkleblocks", &[into_json(filter)?, into_json(hash)?, into_json(count)?])
+    }
+
+    /// Like [RpcApi::get_merkle_blocks], but returns the deserialized blocks.
+    fn get_merkle_blocks_decoded(
+        &self,
+        filter: &str,
+        hash: &BlockHash,
+        count: u32,
+    ) -> Result<Vec<dashcore::MerkleBlock>> {
+        self.get_merkle_blocks(filter, hash, count)?
+            .iter()
+            .map(|hex| {
+                let bytes: Vec<u8> = FromHex::from_hex(hex)?;
+                Ok(dashcore::consensus::encode::deserialize(&bytes)?)
+            })
+            .collect()
+    }
+
     fn import_public_key(
         &self,
         pubkey: &PublicKey,
@@ -651,6 +1011,21 @@ pub trait RpcApi: Sized {
         self.call("importmulti", handle_defaults(&mut args, &[null()]))
     }
 
+    /// Imports descriptors into a descriptor wallet.
+    fn import_descriptors(
+        &self,
+        requests: &[json::ImportDescriptorRequest],
+    ) -> Result<Vec<json::ImportMultiResult>> {
+        self.call("importdescriptors", &[into_json(requests)?])
+    }
+
+    /// Lists the descriptors of a descriptor wallet, with their private keys
+    /// if `private` is true.
+    fn list_descriptors(&self, private: Option<bool>) -> Result<json::ListDescriptorsResult> {
+        let mut args = [opt_into_json(private)?];
+        self.call("listdescriptors", handle_defaults(&mut args, &[null()]))
+    }
+
     fn set_label(&self, address: &Address, label: &str) -> Result<()> {
         self.call("setlabel", &[address.to_string().into(), label.into()])
     }
@@ -679,6 +1054,33 @@ pub trait RpcApi: Sized {
         self.call("listunspent", handle_defaults(&mut args, &defaults))
     }
 
+    /// Returns the spendable unspent outputs of the wallet, counting unconfirmed
+    /// outputs as spendable once their transaction is InstantSend locked.
+    fn list_spendable_with_instantsend(&self) -> Result<Vec<json::ListUnspentResultEntry>> {
+        let mut locked = HashMap::new();
+        let mut spendable = Vec::new();
+        for entry in self.list_unspent(Some(0), None, None, Some(true), None)? {
+            if !entry.spendable {
+                continue;
+            }
+            if entry.confirmations == 0 {
+                let is_locked = match locked.get(&entry.txid) {
+                    Some(is_locked) => *is_locked,
+                    None => {
+                        let info = self.get_raw_transaction_info(&entry.txid, None)?;
+                        locked.insert(entry.txid, info.instantlock);
+                        info.instantlock
+                    }
+                };
+                if !is_locked {
+                    continue;
+                }
+            }
+            spendable.push(entry);
+        }
+        Ok(spendable)
+    }
+
     /// To unlock, use [unlock_unspent].
     fn lock_unspent(&self, outputs: &[OutPoint]) -> Result<bool> {
         let outputs: Vec<_> = outputs
@@ -745,6 +1147,22 @@ pub trait RpcApi: Sized {
         Ok(dashcore::consensus::encode::deserialize(&bytes)?)
     }
 
+    /// Decodes a raw transaction the way the node understands it.
+    /// `is_witness` is only accepted by nodes with segwit support.
+    fn decode_raw_transaction(
+        &self,
+        hex: &str,
+        is_witness: Option<bool>,
+    ) -> Result<json::GetRawTransactionResult> {
+        let mut args = [into_json(hex)?, opt_into_json(is_witness)?];
+        self.call("decoderawtransaction", handle_defaults(&mut args, &[null()]))
+    }
+
+    /// Decodes a hex-encoded script.
+    fn decode_script(&self, hex: &str) -> Result<json::DecodeScriptResult> {
+        self.call("decodescript", &[into_json(hex)?])
+    }
+
     fn fund_raw_transaction<R: RawTx>(
         &self,
         tx: R,
@@ -755,6 +1173,21 @@ pub trait RpcApi: Sized {
         self.call("fundrawtransaction", handle_defaults(&mut args, &defaults))
     }
 
+    /// Replaces the unconfirmed wallet transaction `txid` with one paying a
+    /// higher fee.
+    ///
+    /// Dash Core does not implement `bumpfee`, as it has no replace-by-fee, so
+    /// dashd always answers with a method not found error. The method exists
+    /// only for forks that do implement it.
+    fn bump_fee(
+        &self,
+        txid: &dashcore::Txid,
+        options: Option<&json::BumpFeeOptions>,
+    ) -> Result<json::BumpFeeResult> {
+        let mut args = [into_json(txid)?, opt_into_json(options)?];
+        self.call("bumpfee", handle_defaults(&mut args, &[null()]))
+    }
+
     fn sign_raw_transaction_with_wallet<R: RawTx>(
         &self,
         tx: R,
@@ -810,6 +1243,12 @@ pub trait RpcApi: Sized {
         self.call("getnewaddress", &[opt_into_json(label)?])
     }
 
+    /// Like [RpcApi::get_new_address], but checks the address against the node's
+    /// [RpcApi::network].
+    fn get_new_address_checked(&self, label: Option<&str>) -> Result<Address> {
+        require_network(self.get_new_address(label)?, self.network()?)
+    }
+
     fn get_address_info(&self, address: &Address) -> Result<json::GetAddressInfoResult> {
         self.call("getaddressinfo", &[address.to_string().into()])
     }
@@ -837,16 +1276,69 @@ pub trait RpcApi: Sized {
         self.call("reconsiderblock", &[into_json(block_hash)?])
     }
 
+    /// Treat `block_hash` as if it were received before others with the same
+    /// work, so it wins ties between competing chains
+    fn precious_block(&self, block_hash: &BlockHash) -> Result<()> {
+        self.call("preciousblock", &[into_json(block_hash)?])
+    }
+
+    /// Prune the blockchain up to `height`, on a node running in prune mode.
+    /// Returns the height of the last block pruned.
+    fn prune_blockchain(&self, height: u64) -> Result<u64> {
+        self.call("pruneblockchain", &[into_json(height)?])
+    }
+
     /// Get txids of all transactions in a memory pool
     fn get_raw_mempool(&self) -> Result<Vec<dashcore::Txid>> {
         self.call("getrawmempool", &[])
     }
 
+    /// Get details for all transactions in the memory pool
+    fn get_raw_mempool_verbose(
+        &self,
+    ) -> Result<HashMap<dashcore::Txid, json::GetMempoolEntryResult>> {
+        self.call("getrawmempool", &[into_json(true)?])
+    }
+
+    /// Get the transactions in the memory pool whose initial broadcast has not
+    /// been acknowledged by any peer yet
+    fn get_unbroadcast_txids(&self) -> Result<Vec<dashcore::Txid>> {
+        Ok(self
+            .get_raw_mempool_verbose()?
+            .into_iter()
+            .filter(|(_, entry)| entry.unbroadcast == Some(true))
+            .map(|(txid, _)| txid)
+            .collect())
+    }
+
+    /// Get details on the state of the memory pool
+    fn get_mempool_info(&self) -> Result<json::GetMempoolInfoResult> {
+        self.call("getmempoolinfo", &[])
+    }
+
+    /// Dump the mempool to disk, so it is reloaded when the node restarts.
+    /// Fails if a dump is already in progress.
+    fn save_mempool(&self) -> Result<()> {
+        self.call("savemempool", &[])
+    }
+
     /// Get mempool data for given transaction
     fn get_mempool_entry(&self, txid: &dashcore::Txid) -> Result<json::GetMempoolEntryResult> {
         self.call("getmempoolentry", &[into_json(txid)?])
     }
 
+    /// Compute statistics about the total number and rate of transactions in
+    /// the chain, over the `nblocks` blocks ending at `blockhash` (by default
+    /// one month ending at the tip)
+    fn get_chain_tx_stats(
+        &self,
+        nblocks: Option<u32>,
+        blockhash: Option<&BlockHash>,
+    ) -> Result<json::GetChainTxStatsResult> {
+        let mut args = [opt_into_json(nblocks)?, opt_into_json(blockhash)?];
+        self.call("getchaintxstats", handle_defaults(&mut args, &[null()]))
+    }
+
     /// Get information about all known tips in the block tree, including the
     /// main chain as well as stale branches.
     fn get_chain_tips(&self) -> Result<json::GetChainTipsResult> {
@@ -897,6 +1389,65 @@ pub trait RpcApi: Sized {
         )
     }
 
+    /// Send `amount` to `address` with InstantSend. Wait for the lock with
+    /// [RpcApi::wait_for_instantlock].
+    ///
+    /// This uses `sendtoaddress`, as Dash Core dropped `instantsendtoaddress`
+    /// when InstantSend became the default.
+    fn instant_send_to_address(
+        &self,
+        address: &Address,
+        amount: Amount,
+        comment: Option<&str>,
+        comment_to: Option<&str>,
+        subtract_fee: Option<bool>,
+    ) -> Result<dashcore::Txid> {
+        self.send_to_address(
+            address,
+            amount,
+            comment,
+            comment_to,
+            subtract_fee,
+            Some(true),
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    /// Send to several addresses in a single transaction. `amounts` maps
+    /// each address to the amount it receives.
+    fn send_many(
+        &self,
+        amounts: &HashMap<String, Amount>,
+        comment: Option<&str>,
+        subtract_fee_from: Option<&[&str]>,
+        use_instant_send: Option<bool>,
+        use_coinjoin: Option<bool>,
+        confirmation_target: Option<u32>,
+        estimate_mode: Option<json::EstimateMode>,
+    ) -> Result<dashcore::Txid> {
+        let amounts_converted = serde_json::Map::from_iter(
+            amounts.iter().map(|(k, v)| (k.clone(), serde_json::Value::from(v.to_dash()))),
+        );
+        let mut args = [
+            "".into(),
+            into_json(amounts_converted)?,
+            1.into(),
+            false.into(),
+            opt_into_json(comment)?,
+            opt_into_json(subtract_fee_from)?,
+            opt_into_json(use_instant_send)?,
+            opt_into_json(use_coinjoin)?,
+            opt_into_json(confirmation_target)?,
+            opt_into_json(estimate_mode)?,
+        ];
+        let defaults =
+            ["".into(), empty_arr(), true.into(), false.into(), 6.into(), "UNSET".into()];
+        self.call("sendmany", handle_defaults(&mut args, &defaults))
+    }
+
     /// Attempts to add a node to the addnode list.
     /// Nodes added using addnode (or -connect) are protected from DoS disconnection and are not required to be full nodes/support SegWit as other outbound peers are (though such peers will not be synced from).
     fn add_node(&self, addr: &str) -> Result<()> {
@@ -989,7 +1540,8 @@ pub trait RpcApi: Sized {
     }
 
     fn send_raw_transaction<R: RawTx>(&self, tx: R) -> Result<dashcore::Txid> {
-        self.call("sendrawtransaction", &[tx.raw_hex().into()])
+        let req = requests::send_raw_transaction(tx);
+        self.call(req.cmd, &req.args)
     }
 
     fn estimate_smart_fee(
@@ -1001,6 +1553,36 @@ pub trait RpcApi: Sized {
         self.call("estimatesmartfee", handle_defaults(&mut args, &[null()]))
     }
 
+    /// Set the wallet's transaction fee rate, per kB, used by subsequent
+    /// sends. `fee_rate` must not be zero.
+    fn set_tx_fee(&self, fee_rate: Amount) -> Result<bool> {
+        if fee_rate == Amount::ZERO {
+            return Err(Error::InvalidArgument("settxfee fee rate must be non-zero".to_owned()));
+        }
+        self.call("settxfee", &[into_json(fee_rate.to_dash())?])
+    }
+
+    /// Returns the smart fee estimate for `conf_target` blocks, or the node's
+    /// relay fee if no estimate is available yet.
+    fn get_effective_fee_rate(&self, conf_target: u16) -> Result<json::FeeRate> {
+        match self.estimate_smart_fee(conf_target, None)?.fee_rate {
+            Some(fee_rate) => Ok(json::FeeRate::from_per_kb(fee_rate)),
+            None => Ok(json::FeeRate::from_per_kb(self.get_network_info()?.relay_fee)),
+        }
+    }
+
+    /// Estimates the fee of a transaction spending `inputs` P2PKH inputs to
+    /// `outputs` P2PKH outputs at `fee_rate`, without contacting the node.
+    fn estimate_tx_fee(&self, inputs: usize, outputs: usize, fee_rate: json::FeeRate) -> Amount {
+        // Version, type and lock time, plus the input and output counts.
+        const OVERHEAD: usize = 10;
+        // Outpoint, sequence and a script with a signature and public key.
+        const P2PKH_INPUT: usize = 148;
+        // Value and a P2PKH script.
+        const P2PKH_OUTPUT: usize = 34;
+        fee_rate.fee_for_size(OVERHEAD + inputs * P2PKH_INPUT + outputs * P2PKH_OUTPUT)
+    }
+
     /// Waits for a specific new block and returns useful info about it.
     /// Returns the current block on timeout or exit.
     ///
@@ -1087,6 +1669,55 @@ pub trait RpcApi: Sized {
         self.call("finalizepsbt", handle_defaults(&mut args, &[true.into()]))
     }
 
+    /// Creates an unsigned PSBT spending `inputs` to `outputs`, without
+    /// needing a wallet.
+    fn create_psbt(
+        &self,
+        inputs: &[json::CreateRawTransactionInput],
+        outputs: &HashMap<String, Amount>,
+        locktime: Option<i64>,
+    ) -> Result<String> {
+        let outs_converted = serde_json::Map::from_iter(
+            outputs.iter().map(|(k, v)| (k.clone(), serde_json::Value::from(v.to_dash()))),
+        );
+        let mut args = [into_json(inputs)?, into_json(outs_converted)?, opt_into_json(locktime)?];
+        self.call("createpsbt", handle_defaults(&mut args, &[null()]))
+    }
+
+    /// Converts a raw transaction to a PSBT. Unless `permit_sighash` is true,
+    /// this fails if the transaction has signatures.
+    fn convert_to_psbt(&self, hex: &str, permit_sighash: Option<bool>) -> Result<String> {
+        let mut args = [into_json(hex)?, opt_into_json(permit_sighash)?];
+        self.call("converttopsbt", handle_defaults(&mut args, &[null()]))
+    }
+
+    /// Decodes a base64-encoded PSBT, without checking it against the chain.
+    fn decode_psbt(&self, psbt: &str) -> Result<json::DecodePsbtResult> {
+        self.call("decodepsbt", &[into_json(psbt)?])
+    }
+
+    /// Reports what is still missing for the PSBT to be finalized, and who
+    /// should process it next.
+    fn analyze_psbt(&self, psbt: &str) -> Result<json::AnalyzePsbtResult> {
+        self.call("analyzepsbt", &[into_json(psbt)?])
+    }
+
+    /// Joins the inputs and outputs of several PSBTs into one.
+    fn join_psbts(&self, psbts: &[String]) -> Result<String> {
+        self.call("joinpsbts", &[into_json(psbts)?])
+    }
+
+    /// Adds the UTXOs spent by the PSBT's inputs from the UTXO set or the
+    /// mempool, looking up `descriptors` for the scripts they need.
+    fn utxo_update_psbt(
+        &self,
+        psbt: &str,
+        descriptors: Option<&[json::ScanTxOutRequest]>,
+    ) -> Result<String> {
+        let mut args = [into_json(psbt)?, opt_into_json(descriptors)?];
+        self.call("utxoupdatepsbt", handle_defaults(&mut args, &[null()]))
+    }
+
     fn derive_addresses(
         &self,
         descriptor: &str,
@@ -1113,12 +1744,31 @@ pub trait RpcApi: Sized {
         Ok((res.start_height, res.stop_height))
     }
 
+    /// Stops a rescan started by [RpcApi::rescan_blockchain] or an import.
+    /// Returns whether a rescan was running.
+    fn abort_rescan(&self) -> Result<bool> {
+        self.call("abortrescan", &[])
+    }
+
+    /// Marks an unconfirmed wallet transaction that is not in the mempool as
+    /// abandoned, so its inputs can be spent again.
+    fn abandon_transaction(&self, txid: &dashcore::Txid) -> Result<()> {
+        self.call("abandontransaction", &[into_json(txid)?])
+    }
+
     /// Returns statistics about the unspent transaction output set.
     /// This call may take some time.
     fn get_tx_out_set_info(&self) -> Result<json::GetTxOutSetInfoResult> {
         self.call("gettxoutsetinfo", &[])
     }
 
+    /// Verifies the last `nblocks` blocks of the chain at the thoroughness
+    /// `checklevel` (0-4), by default level 3 over 6 blocks
+    fn verify_chain(&self, checklevel: Option<u32>, nblocks: Option<u32>) -> Result<bool> {
+        let mut args = [opt_into_json(checklevel)?, opt_into_json(nblocks)?];
+        self.call("verifychain", handle_defaults(&mut args, &[3.into(), 6.into()]))
+    }
+
     /// Returns information about network traffic, including bytes in, bytes out,
     /// and current time.
     fn get_net_totals(&self) -> Result<json::GetNetTotalsResult> {
@@ -1136,6 +1786,23 @@ pub trait RpcApi: Sized {
         self.call("uptime", &[])
     }
 
+    /// Sets the node's clock to `timestamp` (unix seconds), or back to the
+    /// system clock if `timestamp` is 0. Regtest only.
+    fn set_mock_time(&self, timestamp: i64) -> Result<()> {
+        self.call("setmocktime", &[into_json(timestamp)?])
+    }
+
+    /// Returns whether each debug logging category is enabled, keyed by name
+    fn get_logging(&self) -> Result<HashMap<String, bool>> {
+        self.call("logging", &[])
+    }
+
+    /// Enables the `include` and disables the `exclude` debug logging
+    /// categories. Returns the resulting state of every category.
+    fn set_logging(&self, include: &[&str], exclude: &[&str]) -> Result<HashMap<String, bool>> {
+        self.call("logging", &[into_json(include)?, into_json(exclude)?])
+    }
+
     fn scan_tx_out_set_blocking(
         &self,
         descriptors: &[json::ScanTxOutRequest],
@@ -1143,30 +1810,245 @@ pub trait RpcApi: Sized {
         self.call("scantxoutset", &["start".into(), into_json(descriptors)?])
     }
 
-    // --------------------------- Masternode -------------------------------
+    // -------------------------- Address index -------------------------------
 
-    /// Returns information about the number of known masternodes
-    fn get_masternode_count(&self) -> Result<json::GetMasternodeCountResult> {
-        self.call("masternode", &["count".into()])
-    }
+    /// Returns the balance of the given addresses.
+    /// Requires the node to run with `-addressindex`.
+    fn get_address_balance(&self, addresses: &[&Address]) -> Result<json::GetAddressBalanceResult> {
+        #[derive(Serialize)]
+        struct Argument<'a> {
+            addresses: &'a [&'a Address],
+        }
 
-    /// Returns a list of known masternodes
-    fn get_masternode_list(
-        &self,
-        mode: Option<&str>,
-        filter: Option<&str>,
-    ) -> Result<HashMap<String, json::Masternode>> {
-        let mut args = ["list".into(), into_json(mode)?, opt_into_json(filter)?];
-        self.call::<HashMap<String, json::Masternode>>(
-            "masternode",
-            handle_defaults(&mut args, &["json".into(), null()]),
+        self.call(
+            "getaddressbalance",
+            &[into_json(Argument {
+                addresses,
+            })?],
         )
     }
 
-    /// Returns masternode compatible outputs
-    fn get_masternode_outputs(&self) -> Result<HashMap<String, String>> {
+    /// Returns the ids of all transactions involving the given addresses,
+    /// optionally only those in blocks `start` to `end` (inclusive).
+    /// Requires the node to run with `-addressindex`.
+    fn get_address_txids(
+        &self,
+        addresses: &[&Address],
+        start: Option<u32>,
+        end: Option<u32>,
+    ) -> Result<Vec<dashcore::Txid>> {
+        #[derive(Serialize)]
+        struct Argument<'a> {
+            addresses: &'a [&'a Address],
+            #[serde(skip_serializing_if = "Option::is_none")]
+            start: Option<u32>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            end: Option<u32>,
+        }
+
+        self.call(
+            "getaddresstxids",
+            &[into_json(Argument {
+                addresses,
+                start,
+                end,
+            })?],
+        )
+    }
+
+    /// Returns all balance changes of the given addresses, optionally only
+    /// those in blocks `start` to `end` (inclusive).
+    /// Requires the node to run with `-addressindex`.
+    fn get_address_deltas(
+        &self,
+        addresses: &[&Address],
+        start: Option<u32>,
+        end: Option<u32>,
+    ) -> Result<Vec<json::AddressDelta>> {
+        #[derive(Serialize)]
+        struct Argument<'a> {
+            addresses: &'a [&'a Address],
+            #[serde(skip_serializing_if = "Option::is_none")]
+            start: Option<u32>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            end: Option<u32>,
+        }
+
+        self.call(
+            "getaddressdeltas",
+            &[into_json(Argument {
+                addresses,
+                start,
+                end,
+            })?],
+        )
+    }
+
+    /// Returns all mempool deltas for the given addresses.
+    /// Requires the node to run with `-addressindex`.
+    fn get_address_mempool(
+        &self,
+        addresses: &[&Address],
+    ) -> Result<Vec<json::AddressMempoolDelta>> {
+        #[derive(Serialize)]
+        struct Argument<'a> {
+            addresses: &'a [&'a Address],
+        }
+
+        self.call(
+            "getaddressmempool",
+            &[into_json(Argument {
+                addresses,
+            })?],
+        )
+    }
+
+    /// Returns all unspent outputs of the given addresses.
+    /// Requires the node to run with `-addressindex`.
+    fn get_address_utxos(&self, addresses: &[&Address]) -> Result<Vec<json::AddressUtxo>> {
+        #[derive(Serialize)]
+        struct Argument<'a> {
+            addresses: &'a [&'a Address],
+        }
+
+        self.call(
+            "getaddressutxos",
+            &[into_json(Argument {
+                addresses,
+            })?],
+        )
+    }
+
+    /// Returns all unspent outputs of the given addresses together with the
+    /// chain tip they were computed at.
+    /// Requires the node to run with `-addressindex`.
+    fn get_address_utxos_with_chain_info(
+        &self,
+        addresses: &[&Address],
+    ) -> Result<json::GetAddressUtxosChainInfoResult> {
+        #[derive(Serialize)]
+        struct Argument<'a> {
+            addresses: &'a [&'a Address],
+            #[serde(rename = "chainInfo")]
+            chain_info: bool,
+        }
+
+        self.call(
+            "getaddressutxos",
+            &[into_json(Argument {
+                addresses,
+                chain_info: true,
+            })?],
+        )
+    }
+
+    /// Returns the transaction input that spends output `index` of `txid`.
+    /// Fails if the output is unspent.
+    /// Requires the node to run with `-spentindex`.
+    fn get_spent_info(&self, txid: &dashcore::Txid, index: u32) -> Result<json::SpentInfoResult> {
+        #[derive(Serialize)]
+        struct Argument<'a> {
+            txid: &'a dashcore::Txid,
+            index: u32,
+        }
+
+        self.call(
+            "getspentinfo",
+            &[into_json(Argument {
+                txid,
+                index,
+            })?],
+        )
+    }
+
+    /// Returns the hashes of the blocks with a timestamp between `low` and
+    /// `high`. Use [RpcApi::get_block_hashes_logical] for logical timestamps.
+    /// Requires the node to run with `-timestampindex`.
+    fn get_block_hashes(
+        &self,
+        high: u64,
+        low: u64,
+        options: Option<json::GetBlockHashesOptions>,
+    ) -> Result<Vec<BlockHash>> {
+        if options.map_or(false, |o| o.logical_times) {
+            return Err(Error::InvalidArgument(
+                "use get_block_hashes_logical for logical times".to_owned(),
+            ));
+        }
+        let mut args = [into_json(high)?, into_json(low)?, opt_into_json(options)?];
+        self.call("getblockhashes", handle_defaults(&mut args, &[null()]))
+    }
+
+    /// Like [RpcApi::get_block_hashes], but also returns the logical timestamp
+    /// of each block.
+    fn get_block_hashes_logical(
+        &self,
+        high: u64,
+        low: u64,
+        no_orphans: bool,
+    ) -> Result<Vec<(BlockHash, u64)>> {
+        #[derive(Deserialize)]
+        struct Response {
+            blockhash: BlockHash,
+            logicalts: u64,
+        }
+        let options = json::GetBlockHashesOptions {
+            no_orphans,
+            logical_times: true,
+        };
+        let res: Vec<Response> =
+            self.call("getblockhashes", &[into_json(high)?, into_json(low)?, into_json(options)?])?;
+        Ok(res.into_iter().map(|r| (r.blockhash, r.logicalts)).collect())
+    }
+
+    // --------------------------- Masternode -------------------------------
+
+    /// Returns information about the number of known masternodes
+    fn get_masternode_count(&self) -> Result<json::GetMasternodeCountResult> {
+        self.call("masternode", &["count".into()])
+    }
+
+    /// Returns a list of known masternodes
+    fn get_masternode_list(
+        &self,
+        mode: Option<&str>,
+        filter: Option<&str>,
+    ) -> Result<HashMap<String, json::Masternode>> {
+        let mut args = ["list".into(), into_json(mode)?, opt_into_json(filter)?];
+        self.call::<HashMap<String, json::Masternode>>(
+            "masternode",
+            handle_defaults(&mut args, &["json".into(), null()]),
+        )
+    }
+
+    /// Returns masternode compatible outputs
+    ///
+    /// Accepts both the `{"txid": "vout"}` map and the `["txid-vout"]` list
+    /// returned by different Dash Core versions.
+    fn get_masternode_outputs(&self) -> Result<Vec<OutPoint>> {
         let mut args = ["outputs".into()];
-        self.call::<HashMap<String, String>>("masternode", handle_defaults(&mut args, &[]))
+        match self.call::<Value>("masternode", handle_defaults(&mut args, &[]))? {
+            Value::Object(map) => map
+                .iter()
+                .map(|(txid, vout)| match vout {
+                    Value::String(vout) => parse_outpoint(txid, vout),
+                    vout => parse_outpoint(txid, &vout.to_string()),
+                })
+                .collect(),
+            Value::Array(list) => list
+                .iter()
+                .map(|output| {
+                    let output = output.as_str().ok_or_else(|| {
+                        UnexpectedStructure(format!("invalid masternode output: {}", output))
+                    })?;
+                    let (txid, vout) = output.rsplit_once('-').ok_or_else(|| {
+                        UnexpectedStructure(format!("invalid masternode output: {}", output))
+                    })?;
+                    parse_outpoint(txid, vout)
+                })
+                .collect(),
+            other => Err(UnexpectedStructure(format!("invalid masternode outputs: {}", other))),
+        }
     }
 
     /// Returns an array of deterministic masternodes and their payments for the specified block
@@ -1220,11 +2102,8 @@ pub trait RpcApi: Sized {
         &self,
         count: Option<u8>,
     ) -> Result<json::QuorumListResult<Vec<QuorumHash>>> {
-        let mut args = ["list".into(), opt_into_json(count)?];
-        self.call::<json::QuorumListResult<Vec<QuorumHash>>>(
-            "quorum",
-            handle_defaults(&mut args, &[1.into(), null()]),
-        )
+        let req = requests::get_quorum_list(count)?;
+        self.call(req.cmd, &req.args)
     }
 
     /// Returns an extended list of on-chain quorums
@@ -1232,8 +2111,8 @@ pub trait RpcApi: Sized {
         &self,
         height: Option<u32>,
     ) -> Result<json::ExtendedQuorumListResult> {
-        let mut args = ["listextended".into(), opt_into_json(height)?];
-        self.call::<json::ExtendedQuorumListResult>("quorum", handle_defaults(&mut args, &[]))
+        let req = requests::get_quorum_listextended(height)?;
+        self.call(req.cmd, &req.args)
     }
 
     /// Returns information about a specific quorum
@@ -1243,22 +2122,14 @@ pub trait RpcApi: Sized {
         quorum_hash: &QuorumHash,
         include_sk_share: Option<bool>,
     ) -> Result<json::QuorumInfoResult> {
-        let mut args = [
-            "info".into(),
-            into_json(llmq_type as u8)?,
-            into_json(quorum_hash)?,
-            opt_into_json(include_sk_share)?,
-        ];
-        self.call::<json::QuorumInfoResult>("quorum", handle_defaults(&mut args, &[null()]))
+        let req = requests::get_quorum_info(llmq_type, quorum_hash, include_sk_share)?;
+        self.call(req.cmd, &req.args)
     }
 
     /// Returns the status of the current DKG process
     fn get_quorum_dkgstatus(&self, detail_level: Option<u8>) -> Result<json::QuorumDKGStatus> {
-        let mut args = ["dkgstatus".into(), opt_into_json(detail_level)?];
-        self.call::<json::QuorumDKGStatus>(
-            "quorum",
-            handle_defaults(&mut args, &[0.into(), null()]),
-        )
+        let req = requests::get_quorum_dkgstatus(detail_level)?;
+        self.call(req.cmd, &req.args)
     }
 
     /// Requests threshold-signing for a message
@@ -1270,15 +2141,8 @@ pub trait RpcApi: Sized {
         quorum_hash: Option<&str>,
         submit: Option<bool>,
     ) -> Result<json::QuorumSignResult> {
-        let mut args = [
-            "sign".into(),
-            into_json(llmq_type)?,
-            into_json(id)?,
-            into_json(msg_hash)?,
-            opt_into_json(quorum_hash)?,
-            opt_into_json(submit)?,
-        ];
-        self.call::<json::QuorumSignResult>("quorum", handle_defaults(&mut args, &[null()]))
+        let req = requests::get_quorum_sign(llmq_type, id, msg_hash, quorum_hash, submit)?;
+        self.call(req.cmd, &req.args)
     }
 
     /// Returns the recovered signature for a previous threshold-signing message request
@@ -1288,9 +2152,8 @@ pub trait RpcApi: Sized {
         id: &str,
         msg_hash: &str,
     ) -> Result<json::QuorumSignature> {
-        let mut args =
-            ["getrecsig".into(), into_json(llmq_type)?, into_json(id)?, into_json(msg_hash)?];
-        self.call::<json::QuorumSignature>("quorum", handle_defaults(&mut args, &[null()]))
+        let req = requests::get_quorum_recsig("getrecsig", llmq_type, id, msg_hash)?;
+        self.call(req.cmd, &req.args)
     }
 
     /// Checks for a recovered signature for a previous threshold-signing message request
@@ -1300,9 +2163,8 @@ pub trait RpcApi: Sized {
         id: &str,
         msg_hash: &str,
     ) -> Result<bool> {
-        let mut args =
-            ["hasrecsig".into(), into_json(llmq_type)?, into_json(id)?, into_json(msg_hash)?];
-        self.call::<bool>("quorum", handle_defaults(&mut args, &[null()]))
+        let req = requests::get_quorum_recsig("hasrecsig", llmq_type, id, msg_hash)?;
+        self.call(req.cmd, &req.args)
     }
 
     /// Checks if there is a conflict for a threshold-signing message request
@@ -1312,9 +2174,8 @@ pub trait RpcApi: Sized {
         id: &str,
         msg_hash: &str,
     ) -> Result<bool> {
-        let mut args =
-            ["isconflicting".into(), into_json(llmq_type)?, into_json(id)?, into_json(msg_hash)?];
-        self.call::<bool>("quorum", handle_defaults(&mut args, &[null()]))
+        let req = requests::get_quorum_recsig("isconflicting", llmq_type, id, msg_hash)?;
+        self.call(req.cmd, &req.args)
     }
 
     /// Checks which quorums the given masternode is a member of
@@ -1323,9 +2184,8 @@ pub trait RpcApi: Sized {
         pro_tx_hash: &ProTxHash,
         scan_quorums_count: Option<u8>,
     ) -> Result<json::QuorumMemberOfResult> {
-        let mut args =
-            ["memberof".into(), into_json(pro_tx_hash)?, opt_into_json(scan_quorums_count)?];
-        self.call::<json::QuorumMemberOfResult>("quorum", handle_defaults(&mut args, &[null()]))
+        let req = requests::get_quorum_memberof(pro_tx_hash, scan_quorums_count)?;
+        self.call(req.cmd, &req.args)
     }
 
     /// Returns quorum rotation information
@@ -1335,16 +2195,9 @@ pub trait RpcApi: Sized {
         extra_share: Option<bool>,
         base_block_hash: Option<&str>,
     ) -> Result<json::QuorumRotationInfo> {
-        let mut args = [
-            "rotationinfo".into(),
-            into_json(block_request_hash)?,
-            opt_into_json(extra_share)?,
-            opt_into_json(base_block_hash)?,
-        ];
-        self.call::<json::QuorumRotationInfo>(
-            "quorum",
-            handle_defaults(&mut args, &[false.into(), "".into(), null()]),
-        )
+        let req =
+            requests::get_quorum_rotationinfo(block_request_hash, extra_share, base_block_hash)?;
+        self.call(req.cmd, &req.args)
     }
 
     /// Returns information about the quorum that would/should sign a request
@@ -1353,8 +2206,8 @@ pub trait RpcApi: Sized {
         llmq_type: QuorumType,
         id: &str,
     ) -> Result<json::SelectQuorumResult> {
-        let mut args = ["selectquorum".into(), into_json(llmq_type)?, into_json(id)?];
-        self.call::<json::SelectQuorumResult>("quorum", handle_defaults(&mut args, &[null()]))
+        let req = requests::get_quorum_selectquorum(llmq_type, id)?;
+        self.call(req.cmd, &req.args)
     }
 
     /// Tests if a quorum signature is valid for a request id and a message hash
@@ -1366,41 +2219,122 @@ pub trait RpcApi: Sized {
         signature: &str,
         quorum_hash: Option<QuorumHash>,
         sign_height: Option<u32>,
+    ) -> Result<bool> {
+        let req = requests::get_quorum_verify(
+            llmq_type,
+            id,
+            msg_hash,
+            signature,
+            quorum_hash,
+            sign_height,
+        )?;
+        self.call(req.cmd, &req.args)
+    }
+
+    /// Asks the masternode `pro_tx_hash` for the data of a quorum selected by
+    /// `data_mask`: 1 for the verification vector, 2 for the encrypted
+    /// contributions (of `pro_tx_hash_filter`'s member). Returns whether the
+    /// request was sent.
+    fn get_quorum_getdata(
+        &self,
+        pro_tx_hash: &ProTxHash,
+        llmq_type: QuorumType,
+        quorum_hash: &QuorumHash,
+        data_mask: u16,
+        pro_tx_hash_filter: Option<&ProTxHash>,
     ) -> Result<bool> {
         let mut args = [
-            "verify".into(),
+            "getdata".into(),
+            into_json(pro_tx_hash)?,
             into_json(llmq_type)?,
-            into_json(id)?,
-            into_json(msg_hash)?,
-            into_json(signature)?,
-            opt_into_json(quorum_hash)?,
-            opt_into_json(sign_height)?,
+            into_json(quorum_hash)?,
+            into_json(data_mask)?,
+            opt_into_json(pro_tx_hash_filter)?,
         ];
         self.call::<bool>("quorum", handle_defaults(&mut args, &[null()]))
     }
 
+    /// Makes this node simulate the DKG error `error_type` (e.g. "justify-lie")
+    /// with probability `rate`, for testing
+    fn get_quorum_dkgsimerror(&self, error_type: &str, rate: f64) -> Result<()> {
+        if !(0.0..=1.0).contains(&rate) {
+            return Err(Error::InvalidArgument(format!("dkgsimerror rate out of range: {}", rate)));
+        }
+        self.call("quorum", &["dkgsimerror".into(), into_json(error_type)?, into_json(rate)?])
+    }
+
     // --------------------------- ProTx -------------------------------
 
     /// Returns a diff and a proof between two masternode list
     fn get_protx_diff(&self, base_block: u32, block: u32) -> Result<json::MasternodeDiff> {
-        let mut args = ["diff".into(), into_json(base_block)?, into_json(block)?];
-        self.call::<json::MasternodeDiff>("protx", handle_defaults(&mut args, &[null()]))
+        let req = requests::get_protx_diff(base_block, block)?;
+        self.call(req.cmd, &req.args)
+    }
+
+    /// Returns a diff and a proof between the masternode lists at two block hashes
+    /// using `protx diff`, mirroring the `mnlistdiff` P2P message served to SPV
+    /// clients
+    fn get_mn_list_diff(
+        &self,
+        base_block_hash: &BlockHash,
+        block_hash: &BlockHash,
+    ) -> Result<json::MasternodeDiff> {
+        let args = ["diff".into(), into_json(base_block_hash)?, into_json(block_hash)?];
+        self.call::<json::MasternodeDiff>("protx", &args)
     }
 
     /// Returns a full deterministic masternode list diff between two heigts
     fn get_protx_listdiff(&self, base_block: u32, block: u32) -> Result<json::MasternodeListDiff> {
-        let mut args = ["listdiff".into(), into_json(base_block)?, into_json(block)?];
-        self.call::<json::MasternodeListDiff>(
-            "protx",
-            handle_defaults(&mut args, &[null(), null()]),
-        )
+        let req = requests::get_protx_listdiff(base_block, block)?;
+        self.call(req.cmd, &req.args)
     }
 
     /// Returns a returns detailed information about a deterministic masternode
-    fn get_protx_info(&self, protx_hash: &ProTxHash, block_hash: Option<&BlockHash>) -> Result<json::ProTxInfo> {
-        let mut args = ["info".into(), into_json(protx_hash.to_hex())?, opt_into_json(block_hash)?];
+    fn get_protx_info(
+        &self,
+        protx_hash: &ProTxHash,
+        block_hash: Option<&BlockHash>,
+    ) -> Result<json::ProTxInfo> {
+        let req = requests::get_protx_info(protx_hash, block_hash)?;
+        self.call(req.cmd, &req.args)
+    }
+
+    /// Verifies that `signature` over `message` was made by the operator key
+    /// the masternode registered with `pro_tx_hash` currently has.
+    ///
+    /// Keys of masternodes still on the legacy BLS version are read in the
+    /// legacy serialization. A signature that doesn't decode verifies as `false`.
+    #[cfg(feature = "bls")]
+    fn verify_mn_operator_signature(
+        &self,
+        pro_tx_hash: &ProTxHash,
+        message: &[u8],
+        signature: &BLSSignature,
+    ) -> Result<bool> {
+        let info = self.get_protx_info(pro_tx_hash, None)?;
+        verify_operator_signature(
+            &info.operator_pubkey()?,
+            info.state.is_legacy_bls(),
+            message,
+            signature,
+        )
+    }
 
-        self.call::<json::ProTxInfo>("protx", handle_defaults(&mut args, &[null()]))
+    /// Returns the provider transaction info of the local masternode, or [None]
+    /// if the node is not running as a registered masternode
+    fn get_my_protx_info(&self) -> Result<Option<json::ProTxInfo>> {
+        let status = match self.call::<Value>("masternode", &["status".into()]) {
+            Ok(status) => status,
+            Err(ref e) if is_not_a_masternode(e) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let pro_tx_hash = match status.get("proTxHash").and_then(Value::as_str) {
+            Some(hash) if !hash.bytes().all(|b| b == b'0') => hash,
+            _ => return Ok(None),
+        };
+        let pro_tx_hash = ProTxHash::from_str(pro_tx_hash)
+            .map_err(|e| UnexpectedStructure(format!("invalid proTxHash: {}", e)))?;
+        self.get_protx_info(&pro_tx_hash, None).map(Some)
     }
 
     /// Returns a list of provider transactions
@@ -1410,13 +2344,8 @@ pub trait RpcApi: Sized {
         detailed: Option<bool>,
         height: Option<u32>,
     ) -> Result<json::ProTxList> {
-        let mut args = [
-            "list".into(),
-            opt_into_json(protx_type)?,
-            opt_into_json(detailed)?,
-            opt_into_json(height)?,
-        ];
-        self.call::<json::ProTxList>("protx", handle_defaults(&mut args, &[null()]))
+        let req = requests::get_protx_list(protx_type, detailed, height)?;
+        requests::protx_list_result(detailed, self.call(req.cmd, &req.args)?)
     }
 
     /// Creates a ProRegTx referencing an existing collateral and and sends it to the network
@@ -1477,6 +2406,77 @@ pub trait RpcApi: Sized {
         self.call::<ProTxHash>("protx", handle_defaults(&mut args, &[null()]))
     }
 
+    /// Creates a ProRegTx for an EvoNode referencing an existing collateral and sends it to the network
+    fn get_protx_register_evo(
+        &self,
+        collateral_hash: &str,
+        collateral_index: u32,
+        ip_and_port: &str,
+        owner_address: &str,
+        operator_pub_key: &str,
+        voting_address: &str,
+        operator_reward: f32,
+        payout_address: &str,
+        platform_node_id: &str,
+        platform_p2p_port: u16,
+        platform_http_port: u16,
+        fee_source_address: Option<&str>,
+        submit: Option<bool>,
+    ) -> Result<ProTxHash> {
+        let mut args = [
+            "register_evo".into(),
+            into_json(collateral_hash)?,
+            into_json(collateral_index)?,
+            into_json(ip_and_port)?,
+            into_json(owner_address)?,
+            into_json(operator_pub_key)?,
+            into_json(voting_address)?,
+            into_json(operator_reward)?,
+            into_json(payout_address)?,
+            into_json(platform_node_id)?,
+            into_json(platform_p2p_port)?,
+            into_json(platform_http_port)?,
+            opt_into_json(fee_source_address)?,
+            opt_into_json(submit)?,
+        ];
+        self.call::<ProTxHash>("protx", handle_defaults(&mut args, &["".into(), null()]))
+    }
+
+    /// Creates and funds a ProRegTx for an EvoNode with the 4,000 DASH necessary for its
+    /// collateral and then sends it to the network
+    fn get_protx_register_fund_evo(
+        &self,
+        collateral_address: &str,
+        ip_and_port: &str,
+        owner_address: &str,
+        operator_pub_key: &str,
+        voting_address: &str,
+        operator_reward: f32,
+        payout_address: &str,
+        platform_node_id: &str,
+        platform_p2p_port: u16,
+        platform_http_port: u16,
+        fund_address: Option<&str>,
+        submit: Option<bool>,
+    ) -> Result<ProTxHash> {
+        let mut args = [
+            "register_fund_evo".into(),
+            into_json(collateral_address)?,
+            into_json(ip_and_port)?,
+            into_json(owner_address)?,
+            into_json(operator_pub_key)?,
+            into_json(voting_address)?,
+            into_json(operator_reward)?,
+            into_json(payout_address)?,
+            into_json(platform_node_id)?,
+            into_json(platform_p2p_port)?,
+            into_json(platform_http_port)?,
+            opt_into_json(fund_address)?,
+            opt_into_json(submit)?,
+        ];
+        self.call::<ProTxHash>("protx", handle_defaults(&mut args, &["".into(), null()]))
+    }
+
     /// Creates an unsigned ProTx and a message that must be signed externally
     fn get_protx_register_prepare(
         &self,
@@ -1570,6 +2570,32 @@ pub trait RpcApi: Sized {
         self.call::<ProTxHash>("protx", handle_defaults(&mut args, &[null()]))
     }
 
+    /// Creates and sends a ProUpServTx for an EvoNode, including its Platform service info
+    fn get_protx_update_service_evo(
+        &self,
+        pro_tx_hash: &str,
+        ip_and_port: &str,
+        operator_key: &str,
+        platform_node_id: &str,
+        platform_p2p_port: u16,
+        platform_http_port: u16,
+        operator_payout_address: Option<dashcore::Address>,
+        fee_source_address: Option<dashcore::Address>,
+    ) -> Result<ProTxHash> {
+        let mut args = [
+            "update_service_evo".into(),
+            into_json(pro_tx_hash)?,
+            into_json(ip_and_port)?,
+            into_json(operator_key)?,
+            into_json(platform_node_id)?,
+            into_json(platform_p2p_port)?,
+            into_json(platform_http_port)?,
+            opt_into_json(operator_payout_address)?,
+            opt_into_json(fee_source_address)?,
+        ];
+        self.call::<ProTxHash>("protx", handle_defaults(&mut args, &["".into(), null()]))
+    }
+
     /// Tests if a quorum signature is valid for a ChainLock
     fn get_verifychainlock(
         &self,
@@ -1613,11 +2639,170 @@ pub trait RpcApi: Sized {
     fn mnsync_status(&self) -> Result<json::MnSyncStatus> {
         self.call::<json::MnSyncStatus>("mnsync", &["status".into()])
     }
+
+    // --------------------------- Governance -------------------------------
+
+    /// Returns the governance parameters and the superblock schedule
+    fn get_governance_info(&self) -> Result<json::GetGovernanceInfoResult> {
+        self.call("getgovernanceinfo", &[])
+    }
+
+    /// Returns the treasury budget available to the superblock at `block_height`
+    fn get_superblock_budget(&self, block_height: u32) -> Result<Amount> {
+        let budget: JsonAmount = self.call("getsuperblockbudget", &[into_json(block_height)?])?;
+        Ok(budget.0)
+    }
+
+    /// Returns the governance objects, keyed by hash, matching `signal`
+    /// ("valid" by default) and `type_filter` ("all" by default)
+    fn gobject_list(
+        &self,
+        signal: Option<&str>,
+        type_filter: Option<&str>,
+    ) -> Result<HashMap<String, json::GovernanceObject>> {
+        let mut args = ["list".into(), opt_into_json(signal)?, opt_into_json(type_filter)?];
+        self.call("gobject", handle_defaults(&mut args, &["valid".into(), "all".into()]))
+    }
+
+    /// Returns the governance object with the given hash
+    fn gobject_get(&self, hash: &sha256d::Hash) -> Result<json::GovernanceObject> {
+        let mut object: serde_json::Map<String, Value> =
+            self.call("gobject", &["get".into(), into_json(hash)?])?;
+        // Unlike `gobject list`, the vote tallies are nested by signal here.
+        if let Some(Value::Object(funding)) = object.remove("FundingResult") {
+            object.extend(funding);
+        }
+        Ok(serde_json::from_value(Value::Object(object))?)
+    }
+
+    /// Submits a governance vote signed outside the node by the masternode
+    /// with collateral `mn_txhash:mn_outidx`. Returns the node's status message.
+    fn vote_raw(
+        &self,
+        mn_txhash: &dashcore::Txid,
+        mn_outidx: u32,
+        gov_hash: &sha256d::Hash,
+        vote_signal: &str,
+        vote_outcome: &str,
+        time: u64,
+        sig: &str,
+    ) -> Result<String> {
+        if !["funding", "valid", "delete", "endorsed"].contains(&vote_signal) {
+            return Err(Error::InvalidArgument(format!("unknown vote signal: {}", vote_signal)));
+        }
+        self.call(
+            "voteraw",
+            &[
+                into_json(mn_txhash)?,
+                into_json(mn_outidx)?,
+                into_json(gov_hash)?,
+                into_json(vote_signal)?,
+                into_json(vote_outcome)?,
+                into_json(time)?,
+                into_json(sig)?,
+            ],
+        )
+    }
+
+    // --------------------------- CoinJoin -------------------------------
+
+    /// Returns the wallet's CoinJoin settings and mixing sessions
+    fn get_coinjoin_info(&self) -> Result<json::CoinJoinInfoResult> {
+        self.call("getcoinjoininfo", &[])
+    }
+
+    /// Sends a mixing control command ("start", "stop" or "reset") and returns
+    /// the node's status message
+    ///
+    /// Any other command fails with [Error::InvalidArgument] before a request is
+    /// made, as it is the caller's input that is wrong rather than the node's
+    /// answer, which is what [Error::UnexpectedStructure] is for.
+    fn coinjoin(&self, command: &str) -> Result<String> {
+        if !["start", "stop", "reset"].contains(&command) {
+            return Err(Error::InvalidArgument(format!("unknown coinjoin command: {}", command)));
+        }
+        self.call("coinjoin", &[into_json(command)?])
+    }
+
+    /// Starts mixing
+    fn coinjoin_start(&self) -> Result<String> {
+        self.coinjoin("start")
+    }
+
+    /// Stops mixing
+    fn coinjoin_stop(&self) -> Result<String> {
+        self.coinjoin("stop")
+    }
+
+    /// Resets the mixing state
+    fn coinjoin_reset(&self) -> Result<String> {
+        self.coinjoin("reset")
+    }
+
+    // --------------------------- Sporks -------------------------------
+
+    /// Returns the value of every spork, keyed by name
+    fn get_sporks(&self) -> Result<HashMap<String, i64>> {
+        self.call("spork", &["show".into()])
+    }
+
+    /// Returns whether each spork is active, keyed by name
+    fn get_spork_active(&self) -> Result<HashMap<String, bool>> {
+        self.call("spork", &["active".into()])
+    }
+
+    /// Sets spork `name` to `value` and relays it. Only works on a node
+    /// started with the spork private key.
+    fn set_spork(&self, name: &str, value: i64) -> Result<String> {
+        self.call("sporkupdate", &[into_json(name)?, into_json(value)?])
+    }
+}
+
+/// Information about a finished RPC call, passed to the observer set with
+/// [Client::with_observer].
+#[derive(Debug)]
+pub struct RpcEvent<'a> {
+    /// The RPC command that was called.
+    pub method: &'a str,
+    /// Time spent sending the request and receiving the response. For a call
+    /// sent with [Client::batch_call] this is the time taken by the whole batch.
+    pub duration: Duration,
+    /// The error the call failed with, if any.
+    pub error: Option<&'a Error>,
+    /// Size of the serialized arguments.
+    pub request_bytes: usize,
+    /// Size of the raw result, zero if there was none.
+    pub response_bytes: usize,
+}
+
+/// The callback set with [Client::with_observer].
+pub(crate) type Observer = Box<dyn Fn(&RpcEvent) + Send + Sync>;
+
+/// The RPC commands a client may send, see [Client::with_allowed_methods] and
+/// [Client::with_denied_methods].
+#[derive(Clone, Debug, Default)]
+pub(crate) struct MethodFilter {
+    pub(crate) allowed: Option<HashSet<String>>,
+    pub(crate) denied: HashSet<String>,
+}
+
+impl MethodFilter {
+    /// Fails with [Error::MethodNotAllowed] if `cmd` may not be sent.
+    pub(crate) fn check(&self, cmd: &str) -> Result<()> {
+        if self.denied.contains(cmd)
+            || !self.allowed.as_ref().map_or(true, |allow| allow.contains(cmd))
+        {
+            return Err(Error::MethodNotAllowed(cmd.to_owned()));
+        }
+        Ok(())
+    }
 }
 
 /// Client implements a JSON-RPC client for the Dash Core daemon or compatible APIs.
 pub struct Client {
     client: jsonrpc::client::Client,
+    methods: MethodFilter,
+    observer: Option<Observer>,
 }
 
 impl fmt::Debug for Client {
@@ -1629,20 +2814,164 @@ impl fmt::Debug for Client {
 impl Client {
     /// Creates a client to a dashd JSON-RPC server.
     ///
+    /// Requests time out after the `jsonrpc` default of 15 seconds, see
+    /// [Client::new_with_timeout] to change it.
+    ///
     /// Can only return [Err] when using cookie authentication.
     pub fn new(url: &str, auth: Auth) -> Result<Self> {
         let (user, pass) = auth.get_user_pass()?;
         jsonrpc::client::Client::simple_http(url, user, pass)
-            .map(|client| Client {
-                client,
-            })
+            .map(Client::from_jsonrpc)
             .map_err(|e| super::error::Error::JsonRpc(e.into()))
     }
 
+    /// Creates a client to a dashd JSON-RPC server whose requests time out
+    /// after `timeout`.
+    ///
+    /// The timeout applies to each request on its own, so it has to leave room
+    /// for slow calls like `scantxoutset` or `gettxoutsetinfo`, which can take
+    /// minutes.
+    pub fn new_with_timeout(url: &str, auth: Auth, timeout: Duration) -> Result<Self> {
+        let (user, pass) = auth.get_user_pass()?;
+        let mut builder = jsonrpc::simple_http::SimpleHttpTransport::builder()
+            .url(url)
+            .map_err(|e| super::error::Error::JsonRpc(e.into()))?
+            .timeout(timeout);
+        if let Some(user) = user {
+            builder = builder.auth(user, pass);
+        }
+        Ok(Client::from_transport(builder.build()))
+    }
+
     /// Create a new Client using the given [jsonrpc::Client].
     pub fn from_jsonrpc(client: jsonrpc::client::Client) -> Client {
         Client {
             client,
+            methods: MethodFilter::default(),
+            observer: None,
+        }
+    }
+
+    /// Create a new Client sending its requests over a custom [Transport], e.g.
+    /// a unix socket, a tunnel or an in-process mock.
+    ///
+    /// [Transport]: jsonrpc::client::Transport
+    pub fn from_transport<T: jsonrpc::client::Transport>(transport: T) -> Client {
+        Client::from_jsonrpc(jsonrpc::client::Client::with_transport(transport))
+    }
+
+    /// Call `observer` with an [RpcEvent] after every RPC call that was sent,
+    /// including each call of a [Client::batch_call].
+    ///
+    /// This complements the `log` output, e.g. for collecting latency and error
+    /// metrics.
+    pub fn with_observer(mut self, observer: impl Fn(&RpcEvent) + Send + Sync + 'static) -> Client {
+        self.observer = Some(Box::new(observer));
+        self
+    }
+
+    /// Only allow the given RPC commands to be sent.
+    ///
+    /// Any other command fails with [Error::MethodNotAllowed] before a request
+    /// is made.
+    pub fn with_allowed_methods(mut self, allow: HashSet<String>) -> Client {
+        self.methods.allowed = Some(allow);
+        self
+    }
+
+    /// Forbid the given RPC commands from being sent.
+    ///
+    /// These commands fail with [Error::MethodNotAllowed] before a request is
+    /// made, even if they are also allowed by [Client::with_allowed_methods].
+    pub fn with_denied_methods(mut self, deny: HashSet<String>) -> Client {
+        self.methods.denied = deny;
+        self
+    }
+
+    /// Returns the JSON-RPC request body that [RpcApi::call] would send for `cmd`
+    /// with `args`, without sending it.
+    ///
+    /// Like a sent request, this uses up a request id.
+    pub fn build_request_json(&self, cmd: &str, args: &[Value]) -> Result<Value> {
+        let raw_args = raw_args(args)?;
+        Ok(serde_json::to_value(self.client.build_request(cmd, &raw_args))?)
+    }
+
+    /// Sends `calls` in a single JSON-RPC batch.
+    ///
+    /// Responses are matched to their request by id, so the results are in the
+    /// same order as `calls` whatever order the server answers in. The batch as
+    /// a whole fails if any of the commands is not allowed.
+    pub fn batch_call<T: for<'a> serde::de::Deserialize<'a>>(
+        &self,
+        calls: &[(&str, &[Value])],
+    ) -> Result<Vec<Result<T>>> {
+        for (cmd, _) in calls {
+            self.methods.check(cmd)?;
+        }
+        let cmds = calls.iter().map(|(cmd, _)| *cmd).collect::<Vec<_>>();
+        let raw_args = calls.iter().map(|(_, args)| raw_args(args)).collect::<Result<Vec<_>>>()?;
+        let requests = cmds
+            .iter()
+            .zip(&raw_args)
+            .map(|(cmd, args)| self.client.build_request(cmd, args))
+            .collect::<Vec<_>>();
+        if log_enabled!(Debug) {
+            debug!(target: "dashcore_rpc", "JSON-RPC batch request: {:?}", cmds);
+        }
+
+        let start = Instant::now();
+        let responses = match self.client.send_batch(&requests) {
+            Err(ref e) if can_resend(e, &cmds) => {
+                debug!(target: "dashcore_rpc", "JSON-RPC connection closed, reconnecting: {}", e);
+                self.client.send_batch(&requests)
+            }
+            resp => resp,
+        };
+        let duration = start.elapsed();
+        let responses = match responses {
+            Ok(responses) => responses,
+            Err(e) => {
+                let e = Error::from(e);
+                for (cmd, args) in cmds.iter().zip(&raw_args) {
+                    self.observe(cmd, duration, Some(&e), args, 0);
+                }
+                return Err(e);
+            }
+        };
+        Ok(cmds
+            .iter()
+            .zip(&raw_args)
+            .zip(responses)
+            .map(|((cmd, args), resp)| {
+                let resp = resp
+                    .ok_or_else(|| UnexpectedStructure(format!("no response to {} in batch", cmd)));
+                log_response(cmd, &resp);
+                let response_bytes = response_bytes(&resp);
+                let result = resp.and_then(|resp| Ok(resp.result()?));
+                self.observe(cmd, duration, result.as_ref().err(), args, response_bytes);
+                result
+            })
+            .collect())
+    }
+
+    /// Pass the outcome of a call to the observer, if there is one.
+    fn observe(
+        &self,
+        cmd: &str,
+        duration: Duration,
+        error: Option<&Error>,
+        raw_args: &[Box<serde_json::value::RawValue>],
+        response_bytes: usize,
+    ) {
+        if let Some(ref observer) = self.observer {
+            observer(&RpcEvent {
+                method: cmd,
+                duration,
+                error,
+                request_bytes: raw_args.iter().map(|a| a.get().len()).sum(),
+                response_bytes,
+            });
         }
     }
 
@@ -1655,25 +2984,261 @@ impl Client {
 impl RpcApi for Client {
     /// Call an `cmd` rpc with given `args` list
     fn call<T: for<'a> serde::de::Deserialize<'a>>(&self, cmd: &str, args: &[Value]) -> Result<T> {
-        let raw_args: Vec<_> = args
-            .iter()
-            .map(|a| {
-                let json_string = serde_json::to_string(a)?;
-                serde_json::value::RawValue::from_string(json_string) // we can't use to_raw_value here due to compat with Rust 1.29
-            })
-            .map(|a| a.map_err(|e| Error::Json(e)))
-            .collect::<Result<Vec<_>>>()?;
+        self.methods.check(cmd)?;
+        let raw_args = raw_args(args)?;
         let req = self.client.build_request(&cmd, &raw_args);
         if log_enabled!(Debug) {
-            debug!(target: "dashcore_rpc", "JSON-RPC request: {} {}", cmd, serde_json::Value::from(args));
+            debug!(target: "dashcore_rpc", "JSON-RPC request: {} {}", cmd, loggable_args(cmd, args));
         }
 
-        let resp = self.client.send_request(req).map_err(Error::from);
+        let start = Instant::now();
+        let resp = match self.client.send_request(req) {
+            // The transport drops a connection that failed, so sending the
+            // request again goes over a fresh one.
+            Err(ref e) if can_resend(e, &[cmd]) => {
+                debug!(target: "dashcore_rpc", "JSON-RPC connection closed, reconnecting: {}", e);
+                self.client.send_request(self.client.build_request(&cmd, &raw_args))
+            }
+            resp => resp,
+        }
+        .map_err(Error::from);
         log_response(cmd, &resp);
-        Ok(resp?.result()?)
+        let response_bytes = response_bytes(&resp);
+        let result = resp.and_then(|resp| Ok(resp.result()?));
+        self.observe(cmd, start.elapsed(), result.as_ref().err(), &raw_args, response_bytes);
+        result
+    }
+
+    /// Returns the block together with its height, confirmations and chainlock status,
+    /// fetched in a single batch.
+    fn get_block_with_context(&self, hash: &BlockHash) -> Result<(Block, json::BlockContext)> {
+        let hex_args = [into_json(hash)?, 0.into()];
+        let info_args = [into_json(hash)?, 1.into()];
+        let mut results = self
+            .batch_call::<Value>(&[("getblock", &hex_args[..]), ("getblock", &info_args[..])])?
+            .into_iter();
+        let (hex, info) = match (results.next(), results.next()) {
+            (Some(hex), Some(info)) => (hex?, info?),
+            _ => return Err(UnexpectedStructure("missing getblock results in batch".into())),
+        };
+        let hex: String = serde_json::from_value(hex)?;
+        let info: json::GetBlockResult = serde_json::from_value(info)?;
+        let bytes: Vec<u8> = FromHex::from_hex(&hex)?;
+        let block = dashcore::consensus::encode::deserialize(&bytes)?;
+        Ok((block, json::BlockContext::from(&info)))
+    }
+}
+
+/// Parse the `getspecialtxes` result, picking the variant from the requested
+/// verbosity as an empty list would fit any of them.
+fn special_txes_result(
+    verbosity: Option<u32>,
+    result: Value,
+) -> Result<json::GetSpecialTxesResult> {
+    Ok(match verbosity.unwrap_or(0) {
+        0 => json::GetSpecialTxesResult::Txids(serde_json::from_value(result)?),
+        1 => json::GetSpecialTxesResult::Hex(serde_json::from_value(result)?),
+        _ => json::GetSpecialTxesResult::Transactions(serde_json::from_value(result)?),
+    })
+}
+
+/// Serialize `args` into the raw values sent as request params.
+fn raw_args(args: &[Value]) -> Result<Vec<Box<serde_json::value::RawValue>>> {
+    args.iter()
+        .map(|a| {
+            let json_string = serde_json::to_string(a)?;
+            serde_json::value::RawValue::from_string(json_string) // we can't use to_raw_value here due to compat with Rust 1.29
+        })
+        .map(|a| a.map_err(|e| Error::Json(e)))
+        .collect()
+}
+
+/// Commands whose arguments hold wallet passphrases or keys and must never
+/// be logged.
+const REDACTED_METHODS: &[&str] =
+    &["encryptwallet", "sethdseed", "walletpassphrase", "walletpassphrasechange"];
+
+/// The arguments of `cmd` as they may be logged.
+pub(crate) fn loggable_args(cmd: &str, args: &[Value]) -> Value {
+    if REDACTED_METHODS.contains(&cmd) {
+        Value::from("[redacted]")
+    } else {
+        Value::from(args)
+    }
+}
+
+/// `RPC_INTERNAL_ERROR`, the code `masternode status` fails with on a node that
+/// is not a masternode.
+const RPC_INTERNAL_ERROR: i32 = -32603;
+
+/// The messages `masternode status` fails with on a node that is not a
+/// masternode, across Dash Core versions.
+const NOT_A_MASTERNODE_MESSAGES: &[&str] =
+    &["This is not a masternode", "This node does not run an active masternode"];
+
+/// Whether `e` means the node is not running as a masternode.
+///
+/// The code alone is too generic to go by, so the message has to be one of the
+/// known wordings too.
+fn is_not_a_masternode(e: &Error) -> bool {
+    match *e {
+        Error::RpcError {
+            code,
+            ref message,
+        } if code == RPC_INTERNAL_ERROR => {
+            NOT_A_MASTERNODE_MESSAGES.iter().any(|m| message.starts_with(m))
+        }
+        _ => false,
+    }
+}
+
+/// Commands that only read state, so sending one twice does no harm.
+const READ_ONLY_METHODS: &[&str] = &[
+    "analyzepsbt",
+    "combinepsbt",
+    "converttopsbt",
+    "createmultisig",
+    "createpsbt",
+    "createrawtransaction",
+    "decodepsbt",
+    "decoderawtransaction",
+    "decodescript",
+    "deriveaddresses",
+    "estimatesmartfee",
+    "finalizepsbt",
+    "getaddednodeinfo",
+    "getaddressinfo",
+    "getassetunlockstatuses",
+    "getbalance",
+    "getbalances",
+    "getbestblockhash",
+    "getbestchainlock",
+    "getblock",
+    "getblockchaininfo",
+    "getblockcount",
+    "getblockfilter",
+    "getblockhash",
+    "getblockhashes",
+    "getblockheader",
+    "getblockheaders",
+    "getblockstats",
+    "getchaintips",
+    "getchaintxstats",
+    "getcoinjoininfo",
+    "getconnectioncount",
+    "getdescriptorinfo",
+    "getdifficulty",
+    "getgovernanceinfo",
+    "getislocks",
+    "getmempoolentry",
+    "getmempoolinfo",
+    "getmerkleblocks",
+    "getmininginfo",
+    "getnettotals",
+    "getnetworkhashps",
+    "getnetworkinfo",
+    "getnodeaddresses",
+    "getpeerinfo",
+    "getrawmempool",
+    "getrawtransaction",
+    "getreceivedbyaddress",
+    "getspecialtxes",
+    "getsuperblockbudget",
+    "gettransaction",
+    "gettxchainlocks",
+    "gettxout",
+    "gettxoutproof",
+    "gettxoutsetinfo",
+    "getwalletinfo",
+    "joinpsbts",
+    "listbanned",
+    "listdescriptors",
+    "listreceivedbyaddress",
+    "listsinceblock",
+    "listtransactions",
+    "listunspent",
+    "listwalletdir",
+    "listwallets",
+    "ping",
+    "testmempoolaccept",
+    "uptime",
+    "utxoupdatepsbt",
+    "verifychainlock",
+    "verifyislock",
+    "verifymessage",
+    "verifytxoutproof",
+];
+
+/// Whether a request for `cmds` that failed with `e` can be sent again over a
+/// fresh connection.
+///
+/// A broken pipe means the request could not be written, so the server never
+/// ran it. A reset, abort or early EOF may come after the server already ran
+/// the request, so only read-only commands are sent again then.
+fn can_resend(e: &jsonrpc::Error, cmds: &[&str]) -> bool {
+    use std::io::ErrorKind;
+
+    match closed_connection_kind(e) {
+        Some(ErrorKind::BrokenPipe) => true,
+        Some(_) => cmds.iter().all(|cmd| READ_ONLY_METHODS.contains(cmd)),
+        None => false,
     }
 }
 
+/// The kind of socket error if `e` means the server closed the connection the
+/// request was sent on, as dashd does with connections that were idle for too
+/// long.
+fn closed_connection_kind(e: &jsonrpc::Error) -> Option<std::io::ErrorKind> {
+    use std::io::ErrorKind;
+
+    let e = match *e {
+        jsonrpc::Error::Transport(ref e) => e,
+        _ => return None,
+    };
+    match e.downcast_ref::<jsonrpc::simple_http::Error>() {
+        Some(jsonrpc::simple_http::Error::SocketError(e)) => match e.kind() {
+            kind @ (ErrorKind::BrokenPipe
+            | ErrorKind::ConnectionReset
+            | ErrorKind::ConnectionAborted
+            | ErrorKind::UnexpectedEof) => Some(kind),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Verifies `signature` over `message` against an operator key, which is in the
+/// legacy serialization if `legacy` is set.
+#[cfg(feature = "bls")]
+fn verify_operator_signature(
+    operator_pubkey: &BLSPublicKey,
+    legacy: bool,
+    message: &[u8],
+    signature: &BLSSignature,
+) -> Result<bool> {
+    use dashcore::blsful::{Bls12381G2Impl, PublicKey, SerializationFormat, Signature};
+
+    let pubkey = if legacy {
+        PublicKey::<Bls12381G2Impl>::from_bytes_with_mode(
+            &operator_pubkey[..],
+            SerializationFormat::Legacy,
+        )
+    } else {
+        PublicKey::<Bls12381G2Impl>::try_from(*operator_pubkey)
+    }
+    .map_err(|e| UnexpectedStructure(format!("invalid operator key: {}", e)))?;
+    let signature = match Signature::<Bls12381G2Impl>::try_from(*signature) {
+        Ok(signature) => signature,
+        Err(_) => return Ok(false),
+    };
+    Ok(signature.verify(&pubkey, message).is_ok())
+}
+
+/// Size of the raw result of `resp`, zero if there was none.
+pub(crate) fn response_bytes(resp: &Result<jsonrpc::Response>) -> usize {
+    resp.as_ref().ok().and_then(|r| r.result.as_ref()).map_or(0, |r| r.get().len())
+}
+
 fn log_response(cmd: &str, resp: &Result<jsonrpc::Response>) {
     if log_enabled!(Warn) || log_enabled!(Debug) || log_enabled!(Trace) {
         match resp {
@@ -1718,6 +3283,59 @@ mod tests {
         assert!(client.send_raw_transaction("deadbeef".to_owned()).is_err());
     }
 
+    #[test]
+    fn test_invalid_arguments() {
+        use dashcore::hashes::Hash;
+
+        // Nothing may reach the transport, which would answer with a number.
+        let client = Client::from_transport(CountingTransport);
+        let txid = dashcore::Txid::all_zeros();
+        let gov_hash = sha256d::Hash::all_zeros();
+        let cases: Vec<(&str, Result<()>)> = vec![
+            ("set_tx_fee zero", client.set_tx_fee(Amount::ZERO).map(|_| ())),
+            (
+                "vote_raw signal",
+                client.vote_raw(&txid, 0, &gov_hash, "yes", "yes", 0, "").map(|_| ()),
+            ),
+            ("coinjoin command", client.coinjoin("pause").map(|_| ())),
+            (
+                "getblockhashes logical times",
+                client
+                    .get_block_hashes(
+                        1,
+                        0,
+                        Some(json::GetBlockHashesOptions {
+                            no_orphans: false,
+                            logical_times: true,
+                        }),
+                    )
+                    .map(|_| ()),
+            ),
+            (
+                "dkgsimerror negative rate",
+                client.get_quorum_dkgsimerror("justify-lie", -0.1).map(|_| ()),
+            ),
+            (
+                "dkgsimerror rate above 1",
+                client.get_quorum_dkgsimerror("justify-lie", 1.5).map(|_| ()),
+            ),
+            (
+                "dkgsimerror NaN rate",
+                client.get_quorum_dkgsimerror("justify-lie", f64::NAN).map(|_| ()),
+            ),
+        ];
+        for (case, result) in cases {
+            assert!(matches!(result, Err(Error::InvalidArgument(_))), "{}: {:?}", case, result);
+        }
+    }
+
+    #[test]
+    fn test_loggable_args() {
+        let args = [Value::from("hunter2"), 60.into()];
+        assert!(!loggable_args("walletpassphrase", &args).to_string().contains("hunter2"));
+        assert_eq!(loggable_args("getblockcount", &[]), Value::from(Vec::<Value>::new()));
+    }
+
     fn test_handle_defaults_inner() -> Result<()> {
         {
             let mut args = [into_json(0)?, null(), null()];
@@ -1774,4 +3392,397 @@ mod tests {
     fn test_handle_defaults() {
         test_handle_defaults_inner().unwrap();
     }
+
+    #[test]
+    fn test_estimate_tx_fee() {
+        let client = Client::new("http://localhost/".into(), Auth::None).unwrap();
+        let fee_rate = json::FeeRate::from_per_kb(Amount::from_sat(1000));
+        assert_eq!(client.estimate_tx_fee(1, 2, fee_rate), Amount::from_sat(226));
+        assert_eq!(client.estimate_tx_fee(0, 0, fee_rate), Amount::from_sat(10));
+    }
+
+    #[test]
+    fn test_special_txes_result() {
+        use json::GetSpecialTxesResult;
+
+        let empty = || serde_json::json!([]);
+        assert_eq!(
+            special_txes_result(None, empty()).unwrap(),
+            GetSpecialTxesResult::Txids(vec![])
+        );
+        assert_eq!(
+            special_txes_result(Some(1), empty()).unwrap(),
+            GetSpecialTxesResult::Hex(vec![])
+        );
+        assert_eq!(
+            special_txes_result(Some(2), empty()).unwrap(),
+            GetSpecialTxesResult::Transactions(vec![])
+        );
+    }
+
+    #[test]
+    fn test_parse_outpoint() {
+        let txid = "0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9";
+        let outpoint = parse_outpoint(txid, "1").unwrap();
+        assert_eq!(outpoint.txid.to_string(), txid);
+        assert_eq!(outpoint.vout, 1);
+        assert!(parse_outpoint(txid, "-1").is_err());
+        assert!(parse_outpoint("deadbeef", "1").is_err());
+    }
+
+    #[test]
+    fn test_method_filter() {
+        let methods = |names: &[&str]| names.iter().map(|n| n.to_string()).collect();
+        let client = Client::new("http://localhost/".into(), Auth::None)
+            .unwrap()
+            .with_denied_methods(methods(&["stop", "dumpprivkey"]));
+        assert!(matches!(client.stop(), Err(Error::MethodNotAllowed(ref m)) if m == "stop"));
+        assert!(!matches!(client.get_block_count(), Err(Error::MethodNotAllowed(_))));
+
+        let client = Client::new("http://localhost/".into(), Auth::None)
+            .unwrap()
+            .with_allowed_methods(methods(&["getblockcount", "stop"]))
+            .with_denied_methods(methods(&["stop"]));
+        assert!(!matches!(client.get_block_count(), Err(Error::MethodNotAllowed(_))));
+        assert!(matches!(client.stop(), Err(Error::MethodNotAllowed(_))));
+        assert!(matches!(
+            client.get_best_block_hash(),
+            Err(Error::MethodNotAllowed(ref m)) if m == "getbestblockhash"
+        ));
+    }
+
+    /// Answers every request with the number of params it was sent.
+    struct CountingTransport;
+
+    impl jsonrpc::client::Transport for CountingTransport {
+        fn send_request(
+            &self,
+            req: jsonrpc::Request,
+        ) -> result::Result<jsonrpc::Response, jsonrpc::Error> {
+            Ok(jsonrpc::Response {
+                result: Some(serde_json::value::to_raw_value(&req.params.len()).unwrap()),
+                error: None,
+                id: req.id,
+                jsonrpc: Some("2.0".to_owned()),
+            })
+        }
+
+        fn send_batch(
+            &self,
+            _: &[jsonrpc::Request],
+        ) -> result::Result<Vec<jsonrpc::Response>, jsonrpc::Error> {
+            unimplemented!("batches are not used by Client")
+        }
+
+        fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "counting")
+        }
+    }
+
+    /// Fails the first request with a socket error of the given kind, then
+    /// behaves like [CountingTransport].
+    struct ClosingTransport(std::io::ErrorKind, std::sync::atomic::AtomicBool);
+
+    impl ClosingTransport {
+        fn new(kind: std::io::ErrorKind) -> ClosingTransport {
+            ClosingTransport(kind, Default::default())
+        }
+    }
+
+    impl jsonrpc::client::Transport for ClosingTransport {
+        fn send_request(
+            &self,
+            req: jsonrpc::Request,
+        ) -> result::Result<jsonrpc::Response, jsonrpc::Error> {
+            if !self.1.swap(true, std::sync::atomic::Ordering::SeqCst) {
+                let e = std::io::Error::from(self.0);
+                return Err(jsonrpc::Error::Transport(Box::new(
+                    jsonrpc::simple_http::Error::SocketError(e),
+                )));
+            }
+            jsonrpc::client::Transport::send_request(&CountingTransport, req)
+        }
+
+        fn send_batch(
+            &self,
+            _: &[jsonrpc::Request],
+        ) -> result::Result<Vec<jsonrpc::Response>, jsonrpc::Error> {
+            unimplemented!("batches are not used by Client")
+        }
+
+        fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "closing")
+        }
+    }
+
+    #[test]
+    fn test_reconnect_on_closed_connection() {
+        use std::io::ErrorKind;
+
+        // A request that could not be written is sent again whatever it is.
+        let client = Client::from_transport(ClosingTransport::new(ErrorKind::BrokenPipe));
+        assert_eq!(client.call::<u32>("sendtoaddress", &[1.into()]).unwrap(), 1);
+
+        for kind in
+            [ErrorKind::ConnectionReset, ErrorKind::ConnectionAborted, ErrorKind::UnexpectedEof]
+        {
+            let client = Client::from_transport(ClosingTransport::new(kind));
+            assert_eq!(client.call::<u32>("getblockhash", &[1.into()]).unwrap(), 1, "{:?}", kind);
+
+            // The server may have run it already.
+            let client = Client::from_transport(ClosingTransport::new(kind));
+            assert!(
+                matches!(client.call::<u32>("sendtoaddress", &[1.into()]), Err(Error::JsonRpc(_))),
+                "{:?}",
+                kind
+            );
+        }
+    }
+
+    /// Answers batches like [CountingTransport], in reverse order.
+    struct ReversingTransport;
+
+    impl jsonrpc::client::Transport for ReversingTransport {
+        fn send_request(
+            &self,
+            _: jsonrpc::Request,
+        ) -> result::Result<jsonrpc::Response, jsonrpc::Error> {
+            unimplemented!("only batches are sent")
+        }
+
+        fn send_batch(
+            &self,
+            reqs: &[jsonrpc::Request],
+        ) -> result::Result<Vec<jsonrpc::Response>, jsonrpc::Error> {
+            reqs.iter()
+                .rev()
+                .map(|req| {
+                    jsonrpc::client::Transport::send_request(&CountingTransport, req.clone())
+                })
+                .collect()
+        }
+
+        fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "reversing")
+        }
+    }
+
+    #[test]
+    fn test_batch_call() {
+        use std::sync::{Arc, Mutex};
+
+        let none: &[Value] = &[];
+        let one: &[Value] = &[1.into()];
+        let two: &[Value] = &[1.into(), 2.into()];
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let observed = events.clone();
+        let client = Client::from_transport(ReversingTransport).with_observer(move |event| {
+            observed.lock().unwrap().push((event.request_bytes, event.response_bytes));
+        });
+        let results =
+            client.batch_call::<usize>(&[("echo", none), ("echo", one), ("echo", two)]).unwrap();
+        let results = results.into_iter().map(Result::unwrap).collect::<Vec<_>>();
+        assert_eq!(results, vec![0, 1, 2]);
+        assert_eq!(*events.lock().unwrap(), vec![(0, 1), (1, 1), (2, 1)]);
+
+        let client = client.with_denied_methods(["stop".to_string()].into_iter().collect());
+        assert!(matches!(
+            client.batch_call::<usize>(&[("echo", none), ("stop", none)]),
+            Err(Error::MethodNotAllowed(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_transport() {
+        let client = Client::from_transport(CountingTransport);
+        assert_eq!(client.get_block_count().unwrap(), 0);
+        assert_eq!(client.call::<u32>("echo", &[1.into(), 2.into()]).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_new_with_timeout() {
+        use std::time::Instant;
+
+        // The OS accepts the connection, but nothing ever answers on it.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let timeout = Duration::from_millis(200);
+        let client = Client::new_with_timeout(&url, Auth::None, timeout).unwrap();
+
+        let start = Instant::now();
+        assert!(client.get_block_count().is_err());
+        let elapsed = start.elapsed();
+        assert!(elapsed >= timeout, "failed after {:?}", elapsed);
+        assert!(elapsed < Duration::from_secs(5), "failed after {:?}", elapsed);
+        drop(listener);
+    }
+
+    #[test]
+    fn test_build_request_json() {
+        let client = Client::new("http://localhost/".into(), Auth::None).unwrap();
+        let args = ["info".into(), into_json(QuorumType::LlmqTest as u8).unwrap()];
+        let request = client.build_request_json("quorum", &args).unwrap();
+        assert_eq!(request["method"], "quorum");
+        assert_eq!(request["params"], serde_json::json!(["info", 100]));
+        assert!(request.get("id").is_some());
+    }
+
+    #[test]
+    fn test_observer() {
+        use std::sync::{Arc, Mutex};
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let observed = events.clone();
+        let client = Client::from_transport(CountingTransport)
+            .with_denied_methods(["stop".to_string()].into_iter().collect())
+            .with_observer(move |event| {
+                observed.lock().unwrap().push((
+                    event.method.to_string(),
+                    event.error.is_some(),
+                    event.request_bytes,
+                ));
+            });
+
+        assert_eq!(client.get_block_count().unwrap(), 0);
+        // The transport answers with a number, which is not a block hash.
+        assert!(client.get_block_hash(1).is_err());
+        assert!(client.stop().is_err());
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![("getblockcount".to_string(), false, 0), ("getblockhash".to_string(), true, 1)]
+        );
+    }
+
+    struct PagedRpc {
+        items: Vec<u32>,
+        requests: std::cell::RefCell<Vec<Vec<Value>>>,
+    }
+
+    impl RpcApi for PagedRpc {
+        fn call<T: for<'a> serde::de::Deserialize<'a>>(
+            &self,
+            cmd: &str,
+            args: &[Value],
+        ) -> Result<T> {
+            assert_eq!(cmd, "listtransactions");
+            self.requests.borrow_mut().push(args.to_vec());
+            let count = args[1].as_u64().unwrap() as usize;
+            let skip = args[2].as_u64().unwrap() as usize;
+            let page: Vec<u32> = self.items.iter().skip(skip).take(count).cloned().collect();
+            Ok(serde_json::from_value(into_json(page)?)?)
+        }
+    }
+
+    #[test]
+    fn test_paginate() {
+        let rpc = PagedRpc {
+            items: (0..7).collect(),
+            requests: Default::default(),
+        };
+        let pages: Vec<Vec<u32>> =
+            rpc.paginate("listtransactions", &["*".into()], 3).collect::<Result<_>>().unwrap();
+        assert_eq!(pages, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+        assert_eq!(
+            *rpc.requests.borrow(),
+            vec![
+                vec![Value::from("*"), 3.into(), 0.into()],
+                vec![Value::from("*"), 3.into(), 3.into()],
+                vec![Value::from("*"), 3.into(), 6.into()],
+            ]
+        );
+
+        let rpc = PagedRpc {
+            items: (0..6).collect(),
+            requests: Default::default(),
+        };
+        let pages: Vec<Vec<u32>> =
+            rpc.paginate("listtransactions", &["*".into()], 3).collect::<Result<_>>().unwrap();
+        assert_eq!(pages, vec![vec![0, 1, 2], vec![3, 4, 5], vec![]]);
+    }
+
+    /// Answers every request with the given JSON-RPC error.
+    struct RpcErrorTransport(i32, &'static str);
+
+    impl jsonrpc::client::Transport for RpcErrorTransport {
+        fn send_request(
+            &self,
+            req: jsonrpc::Request,
+        ) -> result::Result<jsonrpc::Response, jsonrpc::Error> {
+            Ok(jsonrpc::Response {
+                result: None,
+                error: Some(jsonrpc::error::RpcError {
+                    code: self.0,
+                    message: self.1.to_owned(),
+                    data: None,
+                }),
+                id: req.id,
+                jsonrpc: Some("2.0".to_owned()),
+            })
+        }
+
+        fn send_batch(
+            &self,
+            _: &[jsonrpc::Request],
+        ) -> result::Result<Vec<jsonrpc::Response>, jsonrpc::Error> {
+            unimplemented!("batches are not used by Client")
+        }
+
+        fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "rpc error")
+        }
+    }
+
+    #[test]
+    fn test_get_my_protx_info_not_a_masternode() {
+        for &message in NOT_A_MASTERNODE_MESSAGES {
+            let client = Client::from_transport(RpcErrorTransport(RPC_INTERNAL_ERROR, message));
+            assert_eq!(client.get_my_protx_info().unwrap(), None, "{}", message);
+        }
+        let client =
+            Client::from_transport(RpcErrorTransport(RPC_INTERNAL_ERROR, "Something went wrong"));
+        assert!(client.get_my_protx_info().is_err());
+        let client = Client::from_transport(RpcErrorTransport(-8, "This is not a masternode"));
+        assert!(client.get_my_protx_info().is_err());
+    }
+
+    #[test]
+    fn test_rpc_error_code() {
+        let err = Error::from(jsonrpc::error::Error::Rpc(jsonrpc::error::RpcError {
+            code: -28,
+            message: "Loading block index...".into(),
+            data: None,
+        }));
+        assert_eq!(err.rpc_code(), Some(-28));
+        assert_eq!(err.to_string(), "RPC error -28: Loading block index...");
+
+        let err = Error::from(jsonrpc::error::Error::NonceMismatch);
+        assert_eq!(err.rpc_code(), None);
+    }
+
+    #[cfg(feature = "bls")]
+    #[test]
+    fn test_verify_operator_signature() {
+        use dashcore::blsful::{Bls12381G2Impl, SecretKey, SignatureSchemes};
+
+        let secret = SecretKey::<Bls12381G2Impl>::from_hash(b"operator");
+        let pubkey = BLSPublicKey::try_from(&secret.public_key().0.to_compressed()[..]).unwrap();
+        let message = b"masternode message";
+        let signed = secret.sign(SignatureSchemes::Basic, message).unwrap();
+        let signature = BLSSignature::try_from(&signed.as_raw_value().to_compressed()[..]).unwrap();
+
+        assert!(verify_operator_signature(&pubkey, false, message, &signature).unwrap());
+        let tampered = b"tampered message";
+        assert!(!verify_operator_signature(&pubkey, false, tampered, &signature).unwrap());
+
+        let mut tampered = signature[..].to_vec();
+        tampered[95] ^= 1;
+        let tampered = BLSSignature::try_from(tampered.as_slice()).unwrap();
+        assert!(!verify_operator_signature(&pubkey, false, message, &tampered).unwrap());
+
+        let other = SecretKey::<Bls12381G2Impl>::from_hash(b"other operator");
+        let signed = other.sign(SignatureSchemes::Basic, message).unwrap();
+        let other = BLSSignature::try_from(&signed.as_raw_value().to_compressed()[..]).unwrap();
+        assert!(!verify_operator_signature(&pubkey, false, message, &other).unwrap());
+    }
 }
@@ -23,6 +23,11 @@ pub type FixedSizeLen = usize;
 #[derive(Debug)]
 pub enum Error {
     JsonRpc(jsonrpc::error::Error),
+    /// The server answered with a JSON-RPC error.
+    RpcError {
+        code: i32,
+        message: String,
+    },
     Hex(hex::Error),
     Json(serde_json::error::Error),
     BitcoinSerialization(dashcore::consensus::encode::Error),
@@ -32,11 +37,36 @@ pub enum Error {
     InvalidCookieFile,
     /// The JSON result had an unexpected structure.
     UnexpectedStructure(String),
+    /// The RPC command was rejected by the client's method filter.
+    MethodNotAllowed(String),
+    /// Waiting for the described condition took longer than allowed.
+    Timeout(String),
+    /// An argument was rejected before the request was sent.
+    InvalidArgument(String),
 }
 
 impl From<jsonrpc::error::Error> for Error {
     fn from(e: jsonrpc::error::Error) -> Error {
-        Error::JsonRpc(e)
+        match e {
+            jsonrpc::error::Error::Rpc(e) => Error::RpcError {
+                code: e.code,
+                message: e.message,
+            },
+            e => Error::JsonRpc(e),
+        }
+    }
+}
+
+impl Error {
+    /// The code of the JSON-RPC error the server answered with, if any.
+    pub fn rpc_code(&self) -> Option<i32> {
+        match *self {
+            Error::RpcError {
+                code,
+                ..
+            } => Some(code),
+            _ => None,
+        }
     }
 }
 
@@ -80,6 +110,10 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             Error::JsonRpc(ref e) => write!(f, "JSON-RPC error: {}", e),
+            Error::RpcError {
+                code,
+                ref message,
+            } => write!(f, "RPC error {}: {}", code, message),
             Error::Hex(ref e) => write!(f, "hex decode error: {}", e),
             Error::Json(ref e) => write!(f, "JSON error: {}", e),
             Error::BitcoinSerialization(ref e) => write!(f, "Bitcoin serialization error: {}", e),
@@ -90,6 +124,9 @@ impl fmt::Display for Error {
             Error::UnexpectedStructure(ref e) => {
                 write!(f, "the JSON result had an unexpected structure: {}", e)
             }
+            Error::MethodNotAllowed(ref m) => write!(f, "RPC method not allowed: {}", m),
+            Error::Timeout(ref e) => write!(f, "timed out waiting for {}", e),
+            Error::InvalidArgument(ref e) => write!(f, "invalid argument: {}", e),
         }
     }
 }
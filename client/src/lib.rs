@@ -29,10 +29,15 @@ pub extern crate dashcore_rpc_json;
 pub use dashcore_rpc_json as json;
 pub use json::dashcore;
 
+#[cfg(feature = "async")]
+mod async_client;
 mod client;
 mod error;
 mod queryable;
+mod requests;
 
+#[cfg(feature = "async")]
+pub use async_client::*;
 pub use client::*;
 pub use error::Error;
 pub use queryable::*;
@@ -0,0 +1,216 @@
+// To the extent possible under law, the author(s) have dedicated all
+// copyright and related and neighboring rights to this software to
+// the public domain worldwide. This software is distributed without
+// any warranty.
+//
+// You should have received a copy of the CC0 Public Domain Dedication
+// along with this software.
+// If not, see <http://creativecommons.org/publicdomain/zero/1.0/>.
+//
+
+//! The requests and response handling shared by [RpcApi](crate::RpcApi) and
+//! the asynchronous client, so both send exactly the same arguments.
+
+use serde_json::Value;
+
+use crate::client::{handle_defaults, into_json, null, opt_into_json, RawTx, Result};
+use crate::dashcore::consensus::{encode, Decodable};
+use crate::dashcore::hashes::hex::FromHex;
+use crate::dashcore::{self, BlockHash, ProTxHash, QuorumHash};
+use crate::json::{self, ProTxListType, QuorumType};
+
+/// An RPC command with its arguments, trailing defaults already stripped.
+pub(crate) struct Request {
+    pub(crate) cmd: &'static str,
+    pub(crate) args: Vec<Value>,
+}
+
+impl Request {
+    fn new(cmd: &'static str, args: &mut [Value], defaults: &[Value]) -> Request {
+        Request {
+            cmd,
+            args: handle_defaults(args, defaults).to_vec(),
+        }
+    }
+}
+
+/// Decode a consensus-encoded object sent as hex.
+pub(crate) fn deserialize_hex<T: Decodable>(hex: &str) -> Result<T> {
+    let bytes: Vec<u8> = FromHex::from_hex(hex)?;
+    Ok(encode::deserialize(&bytes)?)
+}
+
+pub(crate) fn get_raw_transaction(
+    txid: &dashcore::Txid,
+    block_hash: Option<&BlockHash>,
+) -> Result<Request> {
+    let mut args = [into_json(txid)?, into_json(false)?, opt_into_json(block_hash)?];
+    Ok(Request::new("getrawtransaction", &mut args, &[null()]))
+}
+
+pub(crate) fn get_raw_transaction_info(
+    txid: &dashcore::Txid,
+    block_hash: Option<&BlockHash>,
+) -> Result<Request> {
+    let mut args = [into_json(txid)?, into_json(true)?, opt_into_json(block_hash)?];
+    Ok(Request::new("getrawtransaction", &mut args, &[null()]))
+}
+
+pub(crate) fn send_raw_transaction<R: RawTx>(tx: R) -> Request {
+    Request {
+        cmd: "sendrawtransaction",
+        args: vec![tx.raw_hex().into()],
+    }
+}
+
+// -------------------------- Quorum -------------------------------
+
+pub(crate) fn get_quorum_list(count: Option<u8>) -> Result<Request> {
+    let mut args = ["list".into(), opt_into_json(count)?];
+    Ok(Request::new("quorum", &mut args, &[1.into(), null()]))
+}
+
+pub(crate) fn get_quorum_listextended(height: Option<u32>) -> Result<Request> {
+    let mut args = ["listextended".into(), opt_into_json(height)?];
+    Ok(Request::new("quorum", &mut args, &[]))
+}
+
+pub(crate) fn get_quorum_info(
+    llmq_type: QuorumType,
+    quorum_hash: &QuorumHash,
+    include_sk_share: Option<bool>,
+) -> Result<Request> {
+    let mut args = [
+        "info".into(),
+        into_json(llmq_type as u8)?,
+        into_json(quorum_hash)?,
+        opt_into_json(include_sk_share)?,
+    ];
+    Ok(Request::new("quorum", &mut args, &[null()]))
+}
+
+pub(crate) fn get_quorum_dkgstatus(detail_level: Option<u8>) -> Result<Request> {
+    let mut args = ["dkgstatus".into(), opt_into_json(detail_level)?];
+    Ok(Request::new("quorum", &mut args, &[0.into(), null()]))
+}
+
+pub(crate) fn get_quorum_sign(
+    llmq_type: QuorumType,
+    id: &str,
+    msg_hash: &str,
+    quorum_hash: Option<&str>,
+    submit: Option<bool>,
+) -> Result<Request> {
+    let mut args = [
+        "sign".into(),
+        into_json(llmq_type)?,
+        into_json(id)?,
+        into_json(msg_hash)?,
+        opt_into_json(quorum_hash)?,
+        opt_into_json(submit)?,
+    ];
+    Ok(Request::new("quorum", &mut args, &[null()]))
+}
+
+/// The `getrecsig`, `hasrecsig` and `isconflicting` subcommands, which all take
+/// the same arguments.
+pub(crate) fn get_quorum_recsig(
+    subcommand: &str,
+    llmq_type: QuorumType,
+    id: &str,
+    msg_hash: &str,
+) -> Result<Request> {
+    let mut args = [subcommand.into(), into_json(llmq_type)?, into_json(id)?, into_json(msg_hash)?];
+    Ok(Request::new("quorum", &mut args, &[null()]))
+}
+
+pub(crate) fn get_quorum_memberof(
+    pro_tx_hash: &ProTxHash,
+    scan_quorums_count: Option<u8>,
+) -> Result<Request> {
+    let mut args = ["memberof".into(), into_json(pro_tx_hash)?, opt_into_json(scan_quorums_count)?];
+    Ok(Request::new("quorum", &mut args, &[null()]))
+}
+
+pub(crate) fn get_quorum_rotationinfo(
+    block_request_hash: &BlockHash,
+    extra_share: Option<bool>,
+    base_block_hash: Option<&str>,
+) -> Result<Request> {
+    let mut args = [
+        "rotationinfo".into(),
+        into_json(block_request_hash)?,
+        opt_into_json(extra_share)?,
+        opt_into_json(base_block_hash)?,
+    ];
+    Ok(Request::new("quorum", &mut args, &[false.into(), "".into(), null()]))
+}
+
+pub(crate) fn get_quorum_selectquorum(llmq_type: QuorumType, id: &str) -> Result<Request> {
+    let mut args = ["selectquorum".into(), into_json(llmq_type)?, into_json(id)?];
+    Ok(Request::new("quorum", &mut args, &[null()]))
+}
+
+pub(crate) fn get_quorum_verify(
+    llmq_type: QuorumType,
+    id: &str,
+    msg_hash: &str,
+    signature: &str,
+    quorum_hash: Option<QuorumHash>,
+    sign_height: Option<u32>,
+) -> Result<Request> {
+    let mut args = [
+        "verify".into(),
+        into_json(llmq_type)?,
+        into_json(id)?,
+        into_json(msg_hash)?,
+        into_json(signature)?,
+        opt_into_json(quorum_hash)?,
+        opt_into_json(sign_height)?,
+    ];
+    Ok(Request::new("quorum", &mut args, &[null()]))
+}
+
+// --------------------------- ProTx -------------------------------
+
+pub(crate) fn get_protx_diff(base_block: u32, block: u32) -> Result<Request> {
+    let mut args = ["diff".into(), into_json(base_block)?, into_json(block)?];
+    Ok(Request::new("protx", &mut args, &[null()]))
+}
+
+pub(crate) fn get_protx_listdiff(base_block: u32, block: u32) -> Result<Request> {
+    let mut args = ["listdiff".into(), into_json(base_block)?, into_json(block)?];
+    Ok(Request::new("protx", &mut args, &[null(), null()]))
+}
+
+pub(crate) fn get_protx_info(
+    protx_hash: &ProTxHash,
+    block_hash: Option<&BlockHash>,
+) -> Result<Request> {
+    let mut args = ["info".into(), into_json(protx_hash.to_hex())?, opt_into_json(block_hash)?];
+    Ok(Request::new("protx", &mut args, &[null()]))
+}
+
+pub(crate) fn get_protx_list(
+    protx_type: Option<ProTxListType>,
+    detailed: Option<bool>,
+    height: Option<u32>,
+) -> Result<Request> {
+    let mut args = [
+        "list".into(),
+        opt_into_json(protx_type)?,
+        opt_into_json(detailed)?,
+        opt_into_json(height)?,
+    ];
+    Ok(Request::new("protx", &mut args, &[null()]))
+}
+
+/// Parse the `protx list` result, picking the variant from the request as an
+/// empty list would fit either.
+pub(crate) fn protx_list_result(detailed: Option<bool>, result: Value) -> Result<json::ProTxList> {
+    if detailed.unwrap_or(false) {
+        Ok(json::ProTxList::Info(serde_json::from_value(result)?))
+    } else {
+        Ok(json::ProTxList::Hex(serde_json::from_value(result)?))
+    }
+}
@@ -15,8 +15,8 @@ extern crate log;
 use log::{trace, Log};
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
+use std::time::Duration;
 
-use dashcore_rpc::jsonrpc::error::Error as JsonRpcError;
 use dashcore_rpc::{
     dashcore::{
         consensus::encode::{deserialize, serialize},
@@ -83,7 +83,10 @@ static LOGGER: StdLogger = StdLogger;
 macro_rules! assert_deprecated {
     ($call:expr) => {
         match $call.unwrap_err() {
-            Error::JsonRpc(JsonRpcError::Rpc(ref e)) if e.code == -32 => {}
+            Error::RpcError {
+                code: -32,
+                ..
+            } => {}
             e => panic!("expected deprecated error for {}, got: {}", stringify!($call), e),
         }
     };
@@ -93,7 +96,10 @@ macro_rules! assert_deprecated {
 macro_rules! assert_not_found {
     ($call:expr) => {
         match $call.unwrap_err() {
-            Error::JsonRpc(JsonRpcError::Rpc(ref e)) if e.code == -32601 => {}
+            Error::RpcError {
+                code: -32601,
+                ..
+            } => {}
             e => panic!("expected method not found error for {}, got: {}", stringify!($call), e),
         }
     };
@@ -103,8 +109,10 @@ macro_rules! assert_not_found {
 macro_rules! assert_error_message {
     ($call:expr, $code:expr, $msg:expr) => {
         match $call.unwrap_err() {
-            Error::JsonRpc(JsonRpcError::Rpc(ref e))
-                if e.code == $code && e.message.contains($msg) => {}
+            Error::RpcError {
+                code,
+                ref message,
+            } if code == $code && message.contains($msg) => {}
             e => panic!("expected '{}' error for {}, got: {}", $msg, stringify!($call), e),
         }
     };
@@ -223,11 +231,17 @@ fn main() {
     // Create/Load test wallet to perform operations on RPC
     match wallet_client.load_wallet(&TEST_WALLET_NAME) {
         Err(e) => match e {
-            dashcore_rpc::Error::JsonRpc(JsonRpcError::Rpc(ref e)) if e.code == -18 => {
+            Error::RpcError {
+                code: -18,
+                ..
+            } => {
                 wallet_client.create_wallet(&TEST_WALLET_NAME, None, None, None, None).unwrap();
                 trace!(target: "integration_test", "Wallet \"{}\" created", TEST_WALLET_NAME.to_string());
             }
-            dashcore_rpc::Error::JsonRpc(JsonRpcError::Rpc(ref e)) if e.code == -35 => {
+            Error::RpcError {
+                code: -35,
+                ..
+            } => {
                 trace!(target: "integration_test", "Wallet \"{}\" already loaded", TEST_WALLET_NAME.to_string());
             }
             _ => {
@@ -290,57 +304,80 @@ fn test_wallet_node_endpoints(wallet_client: &Client) {
     test_get_balances_generate_to_address(wallet_client);
     test_get_best_block_hash(wallet_client);
     test_get_best_chain_lock(wallet_client);
+    test_is_tip_chainlocked(wallet_client);
     test_get_block_count(wallet_client);
     test_get_block_hash(wallet_client);
     // TODO(dashcore): - fails to parse block
     // test_get_block(wallet_client);
     test_get_block_header_get_block_header_info(wallet_client);
+    test_get_block_headers(wallet_client);
+    test_submit_block(wallet_client);
+    test_get_special_txes(wallet_client);
     test_get_block_stats(wallet_client);
     test_get_address_info(wallet_client);
     test_set_label(wallet_client);
     test_send_to_address(wallet_client);
+    test_send_many(wallet_client);
+    test_instant_send_to_address(wallet_client);
     test_get_received_by_address(wallet_client);
     test_list_unspent(wallet_client);
+    test_list_spendable_with_instantsend(wallet_client);
     test_get_difficulty(wallet_client);
     test_get_connection_count(wallet_client);
     test_get_raw_change_address(wallet_client);
     test_get_raw_transaction(wallet_client);
     test_get_raw_mempool(wallet_client);
     test_list_transactions(wallet_client);
+    test_get_transaction(wallet_client);
     test_list_since_block(wallet_client);
     test_get_tx_out(wallet_client);
     // TODO: fix - fails because of a consensus delay when calling `generate_to_address` inside
     // test_get_tx_out_proof(wallet_client);
     test_get_mempool_entry(wallet_client);
+    test_get_mempool_info(wallet_client);
+    test_save_mempool(wallet_client);
     test_lock_unspent_unlock_unspent(wallet_client);
     // TODO: fix
     // test_get_block_filter(wallet_client);
     test_invalidate_block_reconsider_block(wallet_client);
+    test_precious_block(wallet_client);
     test_key_pool_refill(wallet_client);
     test_sign_raw_transaction_with_send_raw_transaction(wallet_client);
     test_create_raw_transaction(wallet_client);
+    test_decode_raw_transaction_decode_script(wallet_client);
     test_fund_raw_transaction(wallet_client);
     test_test_mempool_accept(wallet_client);
     test_wallet_create_funded_psbt(wallet_client);
     test_wallet_process_psbt(wallet_client);
     test_combine_psbt(wallet_client);
     test_finalize_psbt(wallet_client);
+    test_analyze_psbt(wallet_client);
+    test_create_psbt_convert_to_psbt(wallet_client);
     test_list_received_by_address(wallet_client);
     test_scantxoutset(wallet_client);
+    test_create_multisig(wallet_client);
     test_import_public_key(wallet_client);
     test_import_priv_key(wallet_client);
     test_import_address(wallet_client);
     test_import_address_script(wallet_client);
     test_estimate_smart_fee(wallet_client);
+    test_set_tx_fee(wallet_client);
     test_ping(wallet_client);
     test_get_peer_info(wallet_client);
     test_rescan_blockchain(wallet_client);
+    test_abort_rescan(wallet_client);
+    test_get_coinjoin_info(wallet_client);
+    test_abandon_transaction(wallet_client);
     test_create_wallet(wallet_client);
     test_get_tx_out_set_info(wallet_client);
+    test_verify_chain(wallet_client);
     test_get_chain_tips(wallet_client);
+    test_get_chain_tx_stats(wallet_client);
     test_get_net_totals(wallet_client);
     test_get_network_hash_ps(wallet_client);
     test_uptime(wallet_client);
+    test_set_mock_time(wallet_client);
+    test_logging(wallet_client);
     test_getblocktemplate(wallet_client);
     test_add_node(wallet_client);
     test_get_added_node_info(wallet_client);
@@ -386,6 +423,7 @@ fn test_evo_node_endpoints(evo_client: &Client, wallet_client: &Client) {
     test_get_bls_generate(evo_client);
 
     test_get_protx_diff(evo_client);
+    test_get_mn_list_diff(evo_client);
     // TODO: fix - needs real hash
     // test_get_protx_info(evo_client);
 
@@ -395,6 +433,10 @@ fn test_evo_node_endpoints(evo_client: &Client, wallet_client: &Client) {
     // TODO: fix - needs real hash
     // test_get_protx_register_fund(evo_client);
     // TODO: fix - needs real hash
+    // test_get_protx_register_evo(evo_client);
+    // TODO: fix - needs real hash
+    // test_get_protx_register_fund_evo(evo_client);
+    // TODO: fix - needs real hash
     // test_get_protx_register_prepare(evo_client);
     // TODO: fix - needs real hash
     // test_get_protx_register_submit(evo_client);
@@ -404,12 +446,20 @@ fn test_evo_node_endpoints(evo_client: &Client, wallet_client: &Client) {
     // test_get_protx_update_registrar(evo_client);
     // TODO: fix - run masternode
     // test_get_protx_update_service(evo_client);
+    // TODO: fix - run evonode
+    // test_get_protx_update_service_evo(evo_client);
     // TODO: fix - needs real hash
     // test_get_verifychainlock(evo_client);
     // TODO: fix - needs real hash
     // test_get_verifyislock(evo_client);
 
     test_get_asset_unlock_statuses(&evo_client);
+
+    test_get_governance_info(evo_client);
+    test_get_superblock_budget(evo_client);
+    test_gobject_list_get(evo_client);
+
+    test_get_sporks(evo_client);
 }
 
 fn test_get_network_info(cl: &Client) {
@@ -423,6 +473,11 @@ fn test_get_mining_info(cl: &Client) {
 fn test_get_blockchain_info(cl: &Client) {
     let info = cl.get_blockchain_info().unwrap();
     assert_eq!(&info.chain, "regtest");
+
+    for (name, softfork) in &info.softforks {
+        assert_eq!(cl.is_softfork_active(name).unwrap(), softfork.active);
+    }
+    assert!(!cl.is_softfork_active("no_such_softfork").unwrap());
 }
 
 fn test_get_new_address(cl: &Client) {
@@ -431,6 +486,12 @@ fn test_get_new_address(cl: &Client) {
 
     let addr = cl.get_new_address(Some("test")).unwrap().require_network(*NET).unwrap();
     assert_eq!(addr.address_type(), Some(AddressType::P2pkh));
+
+    assert_eq!(cl.network().unwrap(), *NET);
+    let addr = cl.get_new_address_checked(None).unwrap();
+    assert_eq!(addr.address_type(), Some(AddressType::P2pkh));
+    let addr = cl.get_raw_change_address_checked().unwrap();
+    assert_eq!(addr.address_type(), Some(AddressType::P2pkh));
 }
 
 fn test_dump_private_key(cl: &Client) {
@@ -471,6 +532,10 @@ fn test_get_best_chain_lock(cl: &Client) {
     let _ = cl.get_best_chain_lock().unwrap();
 }
 
+fn test_is_tip_chainlocked(cl: &Client) {
+    let _ = cl.is_tip_chainlocked().unwrap();
+}
+
 fn test_get_block_count(cl: &Client) {
     let height = cl.get_block_count().unwrap();
     assert!(height > 0);
@@ -504,6 +569,32 @@ fn test_get_block_header_get_block_header_info(cl: &Client) {
     assert_eq!(info.confirmations, 1);
     assert_eq!(info.next_block_hash, None);
     assert!(info.previous_block_hash.is_some());
+    assert_eq!(info.chainlock, cl.get_block_info(&tip).unwrap().chainlock);
+}
+
+fn test_get_block_headers(cl: &Client) {
+    let start = cl.get_block_hash(1).unwrap();
+    let infos = cl.get_block_headers(&start, 5).unwrap();
+    let headers = cl.get_block_headers_hex(&start, 5).unwrap();
+    assert_eq!(infos.len(), 5);
+    assert_eq!(infos[0].hash, start);
+    assert!(infos.iter().zip(&headers).all(|(info, header)| info.hash == header.block_hash()));
+    assert!(cl.get_block_headers(&start, 2001).is_err());
+}
+
+fn test_get_special_txes(cl: &Client) {
+    let tip = cl.get_best_block_hash().unwrap();
+    let cbtx = json::DashTxType::CbTx as i32;
+    match cl.get_special_txes(&tip, Some(cbtx), None, None, None).unwrap() {
+        json::GetSpecialTxesResult::Txids(txids) => assert_eq!(txids.len(), 1),
+        r => panic!("unexpected result: {:?}", r),
+    }
+    match cl.get_special_txes(&tip, Some(cbtx), None, None, Some(2)).unwrap() {
+        json::GetSpecialTxesResult::Transactions(txs) => {
+            assert_eq!(txs[0].tx_type(), json::DashTxType::CbTx)
+        }
+        r => panic!("unexpected result: {:?}", r),
+    }
 }
 
 fn test_get_block_stats(cl: &Client) {
@@ -591,20 +682,45 @@ fn test_send_to_address(cl: &Client) {
     // let _ = cl.send_to_address(&addr, btc(1), None, None, None, None, None, None,None, Some(true)).unwrap();
 }
 
+fn test_instant_send_to_address(cl: &Client) {
+    let addr = cl.get_new_address(None).unwrap().require_network(*NET).unwrap();
+    let txid = cl.instant_send_to_address(&addr, btc(1), Some("cc"), None, None).unwrap();
+    let poll = Duration::from_millis(100);
+    assert!(cl.wait_for_instantlock(&txid, Duration::from_secs(5), poll).unwrap());
+    assert!(cl.get_transaction(&txid, None).unwrap().instantlock);
+}
+
+fn test_send_many(cl: &Client) {
+    let addr1 = cl.get_new_address(None).unwrap().require_network(*NET).unwrap();
+    let addr2 = cl.get_new_address(None).unwrap().require_network(*NET).unwrap();
+    let mut amounts = HashMap::new();
+    amounts.insert(addr1.to_string(), btc(1));
+    amounts.insert(addr2.to_string(), btc(2));
+    let txid = cl.send_many(&amounts, Some("cc"), None, None, None, None, None).unwrap();
+    let tx = cl.get_raw_transaction(&txid, None).unwrap();
+    assert!(tx.output.iter().any(|o| o.value == btc(1).to_sat()));
+    assert!(tx.output.iter().any(|o| o.value == btc(2).to_sat()));
+
+    let addr1 = addr1.to_string();
+    let _ = cl
+        .send_many(&amounts, None, Some(&[addr1.as_str()]), Some(true), Some(false), Some(3), None)
+        .unwrap();
+}
+
 fn test_get_received_by_address(cl: &Client) {
     let addr = cl.get_new_address(None).unwrap().require_network(*NET).unwrap();
     let _ =
         cl.send_to_address(&addr, btc(1), None, None, None, None, None, None, None, None).unwrap();
-    assert_eq!(cl.get_received_by_address(&addr, Some(0)).unwrap(), btc(1));
-    assert_eq!(cl.get_received_by_address(&addr, Some(1)).unwrap(), btc(0));
+    assert_eq!(cl.get_received_by_address(&addr, Some(0), None).unwrap(), btc(1));
+    assert_eq!(cl.get_received_by_address(&addr, Some(1), None).unwrap(), btc(0));
 
     // TODO: fix - looks like there's a consensus delay when things are running on network of three nodes.
     // let addr2 = cl.get_new_address(None).unwrap()
     //     .require_network(*NET).unwrap();
     // let _ = cl.generate_to_address(1, &addr2).unwrap();
     // sleep(Duration::new(30, 0));
-    // assert_eq!(cl.get_received_by_address(&addr, Some(1)).unwrap(), btc(1));
-    // assert_eq!(cl.get_received_by_address(&addr, None).unwrap(), btc(1));
+    // assert_eq!(cl.get_received_by_address(&addr, Some(1), None).unwrap(), btc(1));
+    // assert_eq!(cl.get_received_by_address(&addr, None, None).unwrap(), btc(1));
 }
 
 fn test_list_unspent(cl: &Client) {
@@ -631,6 +747,18 @@ fn test_list_unspent(cl: &Client) {
     assert_eq!(unspent[0].amount, btc(7));
 }
 
+fn test_list_spendable_with_instantsend(cl: &Client) {
+    let unspent = cl.list_unspent(Some(0), None, None, None, None).unwrap();
+    let spendable = cl.list_spendable_with_instantsend().unwrap();
+    assert!(spendable.len() <= unspent.len());
+    for entry in spendable {
+        assert!(entry.spendable);
+        if entry.confirmations == 0 {
+            assert!(cl.get_raw_transaction_info(&entry.txid, None).unwrap().instantlock);
+        }
+    }
+}
+
 fn test_get_difficulty(cl: &Client) {
     let _ = cl.get_difficulty().unwrap();
 }
@@ -655,6 +783,14 @@ fn test_get_raw_transaction(cl: &Client) {
 
     let info = cl.get_raw_transaction_info(&txid, None).unwrap();
     assert_eq!(info.txid, txid);
+    assert!(info.is_confirmed(0));
+    assert!(!info.is_confirmed(1));
+    assert_eq!(info.is_final(1), info.chainlock);
+
+    let poll = Duration::from_millis(100);
+    if cl.wait_for_instantlock(&txid, Duration::from_secs(5), poll).unwrap() {
+        assert!(cl.get_raw_transaction_info(&txid, None).unwrap().instantlock);
+    }
 
     // TODO: fix - consensus delay in the network of three nodes does not
     //   instantly updating chain and last call to get_raw_transaction_info
@@ -669,6 +805,18 @@ fn test_get_raw_mempool(cl: &Client) {
     let _ = cl.get_raw_mempool().unwrap();
 }
 
+fn test_get_transaction(cl: &Client) {
+    let txid = cl
+        .send_to_address(&RANDOM_ADDRESS, btc(1), None, None, None, None, None, None, None, None)
+        .unwrap();
+    let tx = cl.get_transaction(&txid, None).unwrap();
+    assert_eq!(tx.info.txid, txid);
+    assert_eq!(tx.amount, -btc(1).to_signed().unwrap());
+    assert!(tx.fee.is_some());
+    assert_eq!(tx.transaction().unwrap().txid(), txid);
+    let _ = cl.get_transaction(&txid, Some(true)).unwrap();
+}
+
 fn test_list_transactions(cl: &Client) {
     let _ = cl.list_transactions(None, None, None, None).unwrap();
     let _ = cl.list_transactions(Some("l"), None, None, None).unwrap();
@@ -705,6 +853,15 @@ fn test_get_tx_out_proof(cl: &Client) {
     let blocks = cl.generate_to_address(7, &addr).unwrap();
     let proof = cl.get_tx_out_proof(&[txid1, txid2], Some(&blocks[0])).unwrap();
     assert!(!proof.is_empty());
+    let mut txids = cl.verify_tx_out_proof(&proof).unwrap();
+    txids.sort();
+    let mut expected = vec![txid1, txid2];
+    expected.sort();
+    assert_eq!(txids, expected);
+}
+
+fn test_save_mempool(cl: &Client) {
+    cl.save_mempool().unwrap();
 }
 
 fn test_get_mempool_entry(cl: &Client) {
@@ -714,10 +871,24 @@ fn test_get_mempool_entry(cl: &Client) {
     let entry = cl.get_mempool_entry(&txid).unwrap();
     assert!(entry.spent_by.is_empty());
 
+    let entries = cl.get_raw_mempool_verbose().unwrap();
+    assert_eq!(entries[&txid].fees, entry.fees);
+    let unbroadcast = cl.get_unbroadcast_txids().unwrap();
+    assert_eq!(unbroadcast.contains(&txid), entry.unbroadcast == Some(true));
+
     let fake = Txid::hash(&[1, 2]);
     assert!(cl.get_mempool_entry(&fake).is_err());
 }
 
+fn test_get_mempool_info(cl: &Client) {
+    cl.send_to_address(&RANDOM_ADDRESS, btc(1), None, None, None, None, None, None, None, None)
+        .unwrap();
+    let info = cl.get_mempool_info().unwrap();
+    assert!(info.size > 0);
+    assert!(info.bytes > 0);
+    assert!(info.mempool_min_fee >= info.min_relay_tx_fee);
+}
+
 fn test_lock_unspent_unlock_unspent(cl: &Client) {
     let addr = cl.get_new_address(None).unwrap().require_network(*NET).unwrap();
     let txid =
@@ -817,11 +988,37 @@ fn test_invalidate_block_reconsider_block(cl: &Client) {
     cl.reconsider_block(&hash).unwrap();
 }
 
+fn test_precious_block(cl: &Client) {
+    let hash = cl.get_best_block_hash().unwrap();
+    cl.precious_block(&hash).unwrap();
+    assert_eq!(cl.get_best_block_hash().unwrap(), hash);
+}
+
 fn test_key_pool_refill(cl: &Client) {
     cl.key_pool_refill(Some(100)).unwrap();
     cl.key_pool_refill(None).unwrap();
 }
 
+fn test_decode_raw_transaction_decode_script(cl: &Client) {
+    let unspent = cl.list_unspent(Some(6), None, None, None, None).unwrap();
+    let input = json::CreateRawTransactionInput {
+        txid: unspent[0].txid,
+        vout: unspent[0].vout,
+        sequence: None,
+    };
+    let mut output = HashMap::new();
+    output.insert(RANDOM_ADDRESS.to_string(), btc(1));
+    let hex = cl.create_raw_transaction_hex(&[input], &output, None).unwrap();
+    let tx = cl.decode_raw_transaction(&hex, None).unwrap();
+    assert_eq!(tx.vin[0].txid, Some(unspent[0].txid.to_string()));
+    assert_eq!(tx.vout[0].value, btc(1));
+
+    let script = hex::encode(&tx.vout[0].script_pub_key.hex);
+    let decoded = cl.decode_script(&script).unwrap();
+    assert_eq!(decoded.script_type, json::ScriptPubkeyType::PubkeyHash);
+    assert_eq!(decoded.addresses[0].clone().assume_checked(), *RANDOM_ADDRESS);
+}
+
 fn test_create_raw_transaction(cl: &Client) {
     let options = json::ListUnspentQueryOptions {
         minimum_amount: Some(btc(2)),
@@ -1023,6 +1220,57 @@ fn test_finalize_psbt(cl: &Client) {
     //assert!(res.hex.is_some());
 }
 
+fn test_analyze_psbt(cl: &Client) {
+    let options = json::ListUnspentQueryOptions {
+        minimum_amount: Some(btc(2)),
+        ..Default::default()
+    };
+    let unspent = cl.list_unspent(Some(6), None, None, None, Some(options)).unwrap();
+    let input = json::CreateRawTransactionInput {
+        txid: unspent[0].txid,
+        vout: unspent[0].vout,
+        sequence: None,
+    };
+    let mut output = HashMap::new();
+    output.insert(RANDOM_ADDRESS.to_string(), btc(1));
+    let psbt = cl.wallet_create_funded_psbt(&[input], &output, None, None, None).unwrap();
+
+    let decoded = cl.decode_psbt(&psbt.psbt).unwrap();
+    assert_eq!(decoded.inputs.len(), decoded.tx.vin.len());
+    assert_eq!(decoded.outputs.len(), decoded.tx.vout.len());
+    assert_eq!(decoded.fee, Some(psbt.fee));
+
+    let updated = cl.utxo_update_psbt(&psbt.psbt, None).unwrap();
+    let analysis = cl.analyze_psbt(&updated).unwrap();
+    assert_eq!(analysis.inputs.len(), 1);
+    assert!(analysis.inputs[0].has_utxo);
+    assert!(!analysis.inputs[0].is_final);
+    assert_eq!(analysis.fee, Some(psbt.fee));
+
+    let input = json::CreateRawTransactionInput {
+        txid: unspent[1].txid,
+        vout: unspent[1].vout,
+        sequence: None,
+    };
+    let other = cl.wallet_create_funded_psbt(&[input], &output, None, None, None).unwrap();
+    let joined = cl.join_psbts(&[psbt.psbt, other.psbt]).unwrap();
+    assert_eq!(cl.analyze_psbt(&joined).unwrap().inputs.len(), 2);
+}
+
+fn test_create_psbt_convert_to_psbt(cl: &Client) {
+    let unspent = cl.list_unspent(Some(6), None, None, None, None).unwrap();
+    let input = json::CreateRawTransactionInput {
+        txid: unspent[0].txid,
+        vout: unspent[0].vout,
+        sequence: None,
+    };
+    let mut output = HashMap::new();
+    output.insert(RANDOM_ADDRESS.to_string(), btc(1));
+    let psbt = cl.create_psbt(&[input.clone()], &output, None).unwrap();
+    let hex = cl.create_raw_transaction_hex(&[input], &output, None).unwrap();
+    assert_eq!(cl.convert_to_psbt(&hex, None).unwrap(), psbt);
+}
+
 fn test_list_received_by_address(cl: &Client) {
     let addr = cl.get_new_address(None).unwrap().require_network(*NET).unwrap();
     let txid =
@@ -1038,6 +1286,22 @@ fn test_list_received_by_address(cl: &Client) {
     assert_eq!(res[0].txids, vec![txid]);
 }
 
+fn test_create_multisig(cl: &Client) {
+    let keys: Vec<_> = (0..3)
+        .map(|_| {
+            PrivateKey {
+                network: Network::Regtest,
+                inner: secp256k1::SecretKey::new(&mut secp256k1::rand::thread_rng()),
+                compressed: true,
+            }
+            .public_key(&SECP)
+        })
+        .collect();
+    let res = cl.create_multisig(2, &keys, None).unwrap();
+    assert!(res.address.is_valid_for_network(*NET));
+    assert!(!res.redeem_script.is_empty());
+}
+
 fn test_import_public_key(cl: &Client) {
     let sk = PrivateKey {
         network: Network::Regtest,
@@ -1085,7 +1349,15 @@ fn test_import_address_script(cl: &Client) {
     cl.import_address_script(&addr.script_pubkey(), None, None, Some(true)).unwrap();
 }
 
+fn test_set_tx_fee(cl: &Client) {
+    assert!(cl.set_tx_fee(Amount::from_sat(10_000)).unwrap());
+    assert!(cl.set_tx_fee(Amount::ZERO).is_err());
+}
+
 fn test_estimate_smart_fee(cl: &Client) {
+    let fee_rate = cl.get_effective_fee_rate(3).unwrap();
+    assert!(fee_rate.per_kb() > btc(0));
+
     let mode = json::EstimateMode::Unset;
     let res = cl.estimate_smart_fee(3, Some(mode)).unwrap();
 
@@ -1122,6 +1394,24 @@ fn test_rescan_blockchain(cl: &Client) {
     assert_eq!(stop, Some(count - 1));
 }
 
+fn test_get_coinjoin_info(cl: &Client) {
+    let info = cl.get_coinjoin_info().unwrap();
+    assert!(!info.running);
+    assert!(info.sessions.is_empty());
+}
+
+fn test_abort_rescan(cl: &Client) {
+    assert!(!cl.abort_rescan().unwrap());
+}
+
+fn test_abandon_transaction(cl: &Client) {
+    let addr = cl.get_new_address(None).unwrap().require_network(*NET).unwrap();
+    let txid =
+        cl.send_to_address(&addr, btc(1), None, None, None, None, None, None, None, None).unwrap();
+    // Only transactions that left the mempool can be abandoned.
+    assert!(cl.abandon_transaction(&txid).is_err());
+}
+
 fn test_create_wallet(cl: &Client) {
     let wallet_names = vec!["alice", "bob", "carol", "denise", "emily"];
 
@@ -1235,18 +1525,38 @@ fn test_create_wallet(cl: &Client) {
     });
 
     // Created wallets
-    assert!(wallet_list.iter().zip(wallet_names).all(|(a, b)| a == b));
+    assert!(wallet_list.iter().zip(&wallet_names).all(|(a, b)| a == b));
+
+    let wallet_dir = cl.list_wallet_dir().unwrap();
+    assert!(wallet_names.iter().all(|w| wallet_dir.iter().any(|d| d == w)));
 }
 
 fn test_get_tx_out_set_info(cl: &Client) {
     cl.get_tx_out_set_info().unwrap();
 }
 
+fn test_verify_chain(cl: &Client) {
+    assert!(cl.verify_chain(None, None).unwrap());
+    assert!(cl.verify_chain(None, Some(10)).unwrap());
+    assert!(cl.verify_chain(Some(4), Some(0)).unwrap());
+}
+
 fn test_get_chain_tips(cl: &Client) {
     let tips = cl.get_chain_tips().unwrap();
     assert_eq!(tips.len(), 1);
 }
 
+fn test_get_chain_tx_stats(cl: &Client) {
+    let best = cl.get_best_block_hash().unwrap();
+    let stats = cl.get_chain_tx_stats(Some(10), None).unwrap();
+    assert_eq!(stats.window_final_block_hash, best);
+    assert_eq!(stats.window_block_count, 10);
+    assert!(stats.window_tx_count.is_some());
+
+    let stats = cl.get_chain_tx_stats(None, Some(&best)).unwrap();
+    assert_eq!(stats.window_final_block_hash, best);
+}
+
 fn test_add_node(cl: &Client) {
     cl.add_node("127.0.0.1:1234").unwrap();
     assert_error_message!(cl.add_node("127.0.0.1:1234"), -23, "Error: Node already added");
@@ -1310,6 +1620,23 @@ fn test_uptime(cl: &Client) {
     cl.uptime().unwrap();
 }
 
+fn test_logging(cl: &Client) {
+    let before = cl.get_logging().unwrap();
+    let after = cl.set_logging(&["net"], &["mempool"]).unwrap();
+    assert_eq!(after.get("net"), Some(&true));
+    assert_eq!(after.get("mempool"), Some(&false));
+
+    let (include, exclude): (Vec<&str>, Vec<&str>) =
+        ["net", "mempool"].into_iter().partition(|c| before[*c]);
+    assert_eq!(cl.set_logging(&include, &exclude).unwrap(), before);
+}
+
+fn test_set_mock_time(cl: &Client) {
+    let tip = cl.get_block_header_info(&cl.get_best_block_hash().unwrap()).unwrap();
+    cl.set_mock_time(tip.time as i64 + 3600).unwrap();
+    cl.set_mock_time(0).unwrap();
+}
+
 fn test_scantxoutset(cl: &Client) {
     let addr = cl.get_new_address(None).unwrap().require_network(*NET).unwrap();
 
@@ -1330,16 +1657,29 @@ fn test_getblocktemplate(cl: &Client) {
     // contains an entry in the vector of GetBlockTemplateResultTransaction.
     // Otherwise the GetBlockTemplateResultTransaction deserialization wouldn't
     // be tested.
-    cl.send_to_address(&RANDOM_ADDRESS, btc(1), None, None, None, None, None, None, None, None)
+    let txid = cl
+        .send_to_address(&RANDOM_ADDRESS, btc(1), None, None, None, None, None, None, None, None)
         .unwrap();
 
-    cl.get_block_template(GetBlockTemplateModes::Template, &[GetBlockTemplateRules::SegWit], &[])
+    let template = cl
+        .get_block_template(GetBlockTemplateModes::Template, &[GetBlockTemplateRules::SegWit], &[])
         .unwrap();
+    assert!(template.transactions.iter().any(|tx| tx.hash == txid));
+    assert!(!cl.is_block_template_stale(&template).unwrap());
 
     // let pop: &Address = &RANDOM_ADDRESS;
 
     // cleanup mempool transaction
     cl.generate_to_address(2, &RANDOM_ADDRESS).unwrap();
+    assert!(cl.is_block_template_stale(&template).unwrap());
+}
+
+fn test_submit_block(cl: &Client) {
+    let tip = cl.get_block_hex(&cl.get_best_block_hash().unwrap()).unwrap();
+    match cl.submit_block_hex(&tip) {
+        Err(Error::UnexpectedStructure(reason)) => assert_eq!(reason, "duplicate"),
+        r => panic!("unexpected result: {:?}", r),
+    }
 }
 
 fn test_stop(cl: &Client) {
@@ -1377,7 +1717,9 @@ fn test_get_masternode_payments(cl: &Client) {
 }
 
 fn test_get_masternode_status(cl: &Client) {
-    let _masternode_status = cl.get_masternode_status().unwrap();
+    let masternode_status = cl.get_masternode_status().unwrap();
+    let my_protx_info = cl.get_my_protx_info().unwrap().unwrap();
+    assert_eq!(my_protx_info.pro_tx_hash, masternode_status.pro_tx_hash);
 }
 
 fn test_get_masternode_winners(cl: &Client) {
@@ -1516,6 +1858,12 @@ fn test_get_protx_diff(cl: &Client) {
     let _protx_diff = cl.get_protx_diff(1000, 1000).unwrap();
 }
 
+fn test_get_mn_list_diff(cl: &Client) {
+    let block_hash = cl.get_block_hash(1000).unwrap();
+    let by_hash = cl.get_mn_list_diff(&block_hash, &block_hash).unwrap();
+    assert_eq!(by_hash, cl.get_protx_diff(1000, 1000).unwrap());
+}
+
 fn test_get_protx_info(cl: &Client) {
     let pro_tx_hash =
         ProTxHash::from_str("000000000c9eddd5d2a707281b7e30d5aac974dac600ff10f01937e1ca36066f")
@@ -1542,8 +1890,8 @@ fn test_get_protx_info(cl: &Client) {
 }
 
 fn test_get_protx_list(cl: &Client) {
-    let _protx_list =
-        cl.get_protx_list(Some(ProTxListType::Valid), Some(true), Some(1000)).unwrap();
+    let protx_list = cl.get_protx_list(Some(ProTxListType::Valid), Some(true), Some(1000)).unwrap();
+    assert!(protx_list.as_info().is_some());
 }
 
 fn test_get_protx_register(cl: &Client) {
@@ -1566,6 +1914,45 @@ fn test_get_protx_register_fund(cl: &Client) {
                                                           5.0, "yUYTxqjpCfAAK4vgxXtBPywRBtZqsxN7Vy", Some("yRMFHxcJ2aS2vfo5whhE2Gg73dfQVm8LAF"), Some(false)).unwrap();
 }
 
+fn test_get_protx_register_evo(cl: &Client) {
+    let _protx_register_evo = cl
+        .get_protx_register_evo(
+            "8b2eab3413abb6e04d17d1defe2b71039ba6b6f72ea1e5dab29bb10e7b745948",
+            1,
+            "2.3.4.5:2345",
+            "yNLuVTXJbjbxgrQX5LSMi7hV19We8hT2d6",
+            "88d719278eef605d9c19037366910b59bc28d437de4a8db4d76fda6d6985dbdf10404fb9bb5cd0e8c22f4a914a6c5566",
+            "yNLuVTXJbjbxgrQX5LSMi7hV19We8hT2d6",
+            5.0,
+            "yjJJLkYDUN6X8gWjXbCoKEXoiLeKxxMMRt",
+            "f2dbd9b0a1f541a7c44d34a58674d0262f5feca5",
+            22821,
+            22822,
+            None,
+            Some(false),
+        )
+        .unwrap();
+}
+
+fn test_get_protx_register_fund_evo(cl: &Client) {
+    let _protx_register_fund_evo = cl
+        .get_protx_register_fund_evo(
+            "yakx4mMRptKhgfjedNzX5FGQq7kSSBF2e7",
+            "3.4.5.6:3456",
+            "yURczr3qY31xkQZfFu8eZvKz19eAEPQxsd",
+            "0e02146e9c34cfbcb3f3037574a1abb35525e2ca0c3c6901dbf82ac591e30218d1711223b7ca956edf39f3d984d06d51",
+            "yURczr3qY31xkQZfFu8eZvKz19eAEPQxsd",
+            5.0,
+            "yUYTxqjpCfAAK4vgxXtBPywRBtZqsxN7Vy",
+            "f2dbd9b0a1f541a7c44d34a58674d0262f5feca5",
+            22821,
+            22822,
+            Some("yRMFHxcJ2aS2vfo5whhE2Gg73dfQVm8LAF"),
+            Some(false),
+        )
+        .unwrap();
+}
+
 fn test_get_protx_register_prepare(cl: &Client) {
     let owner_address = Address::<NetworkUnchecked>::from_str("yemjhGQ99V5ayJMjoyGGPtxteahii6G1Jz")
         .unwrap()
@@ -1643,6 +2030,21 @@ fn test_get_protx_update_service(cl: &Client) {
         .unwrap();
 }
 
+fn test_get_protx_update_service_evo(cl: &Client) {
+    let _protx_update_service_evo = cl
+        .get_protx_update_service_evo(
+            "ba1b3330e16a0876b7a186e7ceb689f03ec646e611e91d7139de021bbf13afdd",
+            "4.3.2.1:4321",
+            "4da7e1ea30fb9e55c73ad23df0b9d3d34342acb24facf4b19420e1a26ae272d1",
+            "f2dbd9b0a1f541a7c44d34a58674d0262f5feca5",
+            22821,
+            22822,
+            None,
+            None,
+        )
+        .unwrap();
+}
+
 fn test_get_verifychainlock(cl: &Client) {
     let _verifychainlock = cl.get_verifychainlock("00000036d5c520be6e9a32d3829efc983a7b5e88052bf138f80a2b3988689a24", "97ec34efd1615b84af62495e54024880752f57790cf450ae974b80002440963592d96826e24f109e6c149411b70bb9a0035443752368590adae60365cf4251464e0423c1263e9c56a33eae9be9e9c79a117151b2173bcee93497008cace8d793", None).unwrap();
 }
@@ -1656,3 +2058,34 @@ fn test_get_asset_unlock_statuses(cl: &Client) {
 fn test_get_verifyislock(cl: &Client) {
     let _verifychainlock = cl.get_verifyislock("d0b1a9c70fdfff6bf7f6cbe3d1fe33a4ca44ceb17059b6381a4ac25d9c9b6495", "8b5174d0e95b5642ebec23c3fe8f0bbf8f6993502f4210322871bba0e818ff3b", "97ec34efd1615b84af62495e54024880752f57790cf450ae974b80002440963592d96826e24f109e6c149411b70bb9a0035443752368590adae60365cf4251464e0423c1263e9c56a33eae9be9e9c79a117151b2173bcee93497008cace8d793", None).unwrap();
 }
+
+// ---------------------- Governance cl tests---------------------
+
+fn test_get_governance_info(cl: &Client) {
+    let info = cl.get_governance_info().unwrap();
+    assert!(info.superblock_cycle > 0);
+    assert!(info.next_superblock > info.last_superblock);
+}
+
+fn test_get_superblock_budget(cl: &Client) {
+    let info = cl.get_governance_info().unwrap();
+    let budget = cl.get_superblock_budget(info.next_superblock).unwrap();
+    assert!(budget > Amount::ZERO);
+}
+
+fn test_gobject_list_get(cl: &Client) {
+    let objects = cl.gobject_list(Some("all"), None).unwrap();
+    for (hash, object) in objects {
+        assert_eq!(hash, object.hash.to_string());
+        assert_eq!(cl.gobject_get(&object.hash).unwrap().hash, object.hash);
+    }
+}
+
+// ---------------------- Spork cl tests---------------------
+
+fn test_get_sporks(cl: &Client) {
+    let sporks = cl.get_sporks().unwrap();
+    let active = cl.get_spork_active().unwrap();
+    assert!(sporks.contains_key("SPORK_2_INSTANTSEND_ENABLED"));
+    assert_eq!(sporks.keys().collect::<HashSet<_>>(), active.keys().collect::<HashSet<_>>());
+}
@@ -22,6 +22,10 @@ extern crate serde;
 extern crate serde_json;
 extern crate serde_with;
 
+use bincode::de::Decoder;
+use bincode::enc::Encoder;
+use bincode::error::{DecodeError, EncodeError};
+use bincode::serde::Compat;
 use bincode::{Decode, Encode};
 use serde_repr::*;
 use std::collections::HashMap;
@@ -34,12 +38,14 @@ use std::str::FromStr;
 use dashcore::address;
 use dashcore::address::NetworkUnchecked;
 use dashcore::block::Version;
+use dashcore::bls_sig_utils::{BLSPublicKey, BLSSignature};
 use dashcore::consensus::encode;
+use dashcore::hash_types::{MerkleRootMasternodeList, MerkleRootQuorums};
 use dashcore::hashes::hex::Error::InvalidChar;
-use dashcore::hashes::sha256;
+use dashcore::hashes::{sha256, sha256d};
 use dashcore::{
-    bip158, bip32, Address, Amount, BlockHash, PrivateKey, ProTxHash, PublicKey, QuorumHash,
-    Script, ScriptBuf, SignedAmount, Transaction, TxMerkleNode, Txid,
+    bip158, bip32, Address, Amount, BlockHash, CompactTarget, Network, PrivateKey, ProTxHash,
+    PublicKey, QuorumHash, Script, ScriptBuf, SignedAmount, Transaction, TxMerkleNode, Txid,
 };
 use hex::FromHexError;
 use serde::de::Error as SerdeError;
@@ -58,6 +64,66 @@ pub struct GetNetworkInfoResultNetwork {
     pub proxy_randomize_credentials: bool,
 }
 
+/// The services a node advertises, reported by Core as a hex string.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct ServiceFlags(pub u64);
+
+impl ServiceFlags {
+    /// Serves the full block chain
+    pub const NETWORK: ServiceFlags = ServiceFlags(1 << 0);
+    /// Answers getutxo requests
+    pub const GETUTXO: ServiceFlags = ServiceFlags(1 << 1);
+    /// Handles bloom-filtered connections
+    pub const BLOOM: ServiceFlags = ServiceFlags(1 << 2);
+    /// Serves compact block filters
+    pub const COMPACT_FILTERS: ServiceFlags = ServiceFlags(1 << 6);
+    /// Serves the last 288 blocks only
+    pub const NETWORK_LIMITED: ServiceFlags = ServiceFlags(1 << 10);
+    /// Serves compressed block headers
+    pub const HEADERS_COMPRESSED: ServiceFlags = ServiceFlags(1 << 11);
+
+    /// Whether all of the services in `flags` are advertised.
+    pub fn has(&self, flags: ServiceFlags) -> bool {
+        self.0 & flags.0 == flags.0
+    }
+
+    pub fn has_network(&self) -> bool {
+        self.has(ServiceFlags::NETWORK)
+    }
+
+    pub fn has_bloom(&self) -> bool {
+        self.has(ServiceFlags::BLOOM)
+    }
+
+    pub fn has_compact_filters(&self) -> bool {
+        self.has(ServiceFlags::COMPACT_FILTERS)
+    }
+
+    pub fn has_network_limited(&self) -> bool {
+        self.has(ServiceFlags::NETWORK_LIMITED)
+    }
+
+    /// The flags in the zero-padded hex form Core reports them in.
+    pub fn as_hex(&self) -> String {
+        format!("{:016x}", self.0)
+    }
+}
+
+impl Serialize for ServiceFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.as_hex())
+    }
+}
+
+impl<'de> Deserialize<'de> for ServiceFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        u64::from_str_radix(&hex, 16)
+            .map(ServiceFlags)
+            .map_err(|e| de::Error::custom(format!("invalid services {}: {}", hex, e)))
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub struct GetNetworkInfoResultAddress {
     pub address: String,
@@ -74,7 +140,7 @@ pub struct GetNetworkInfoResult {
     #[serde(rename = "protocolversion")]
     pub protocol_version: usize,
     #[serde(rename = "localservices")]
-    pub local_services: String,
+    pub local_services: ServiceFlags,
     #[serde(rename = "localservicesnames")]
     pub local_services_names: Vec<String>,
     #[serde(rename = "localrelay")]
@@ -97,9 +163,9 @@ pub struct GetNetworkInfoResult {
     #[serde(rename = "socketevents")]
     pub socket_events: String,
     pub networks: Vec<GetNetworkInfoResultNetwork>,
-    #[serde(rename = "relayfee", with = "dashcore::amount::serde::as_btc")]
+    #[serde(rename = "relayfee", with = "serde_amount::as_btc")]
     pub relay_fee: Amount,
-    #[serde(rename = "incrementalfee", with = "dashcore::amount::serde::as_btc")]
+    #[serde(rename = "incrementalfee", with = "serde_amount::as_btc")]
     pub incremental_fee: Amount,
     #[serde(rename = "localaddresses")]
     pub local_addresses: Vec<GetNetworkInfoResultAddress>,
@@ -113,6 +179,15 @@ pub struct AddMultiSigAddressResult {
     pub redeem_script: ScriptBuf,
 }
 
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateMultisigResult {
+    pub address: Address<NetworkUnchecked>,
+    pub redeem_script: ScriptBuf,
+    /// Only returned by nodes with descriptor support.
+    pub descriptor: Option<String>,
+}
+
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub struct LoadWalletResult {
     pub name: String,
@@ -134,13 +209,13 @@ pub struct GetWalletInfoResult {
     pub wallet_name: String,
     #[serde(rename = "walletversion")]
     pub wallet_version: u32,
-    #[serde(with = "dashcore::amount::serde::as_btc")]
+    #[serde(with = "serde_amount::as_btc")]
     pub balance: Amount,
-    #[serde(with = "dashcore::amount::serde::as_btc")]
+    #[serde(with = "serde_amount::as_btc")]
     pub coinjoin_balance: Amount,
-    #[serde(with = "dashcore::amount::serde::as_btc")]
+    #[serde(with = "serde_amount::as_btc")]
     pub unconfirmed_balance: Amount,
-    #[serde(with = "dashcore::amount::serde::as_btc")]
+    #[serde(with = "serde_amount::as_btc")]
     pub immature_balance: Amount,
     #[serde(rename = "txcount")]
     pub tx_count: usize,
@@ -156,7 +231,7 @@ pub struct GetWalletInfoResult {
     pub unlocked_until: Option<u64>,
     #[serde(rename = "paytxfee")]
     pub pay_tx_fee: f32,
-    #[serde(default, rename = "hdchainid", deserialize_with = "deserialize_hex_opt")]
+    #[serde(default, rename = "hdchainid", with = "serde_hex::opt")]
     pub hd_chainid: Option<Vec<u8>>,
     #[serde(rename = "hdaccountcount")]
     pub hd_account_count: Option<u32>,
@@ -187,17 +262,31 @@ impl Eq for ScanningDetails {}
 pub struct CoinbaseTxDetails {
     pub version: usize,
     pub height: i32,
-    #[serde(rename = "merkleRootMNList", with = "hex")]
-    merkle_root_mn_list: Vec<u8>,
-    #[serde(rename = "merkleRootQuorums", with = "hex")]
-    merkle_root_quorums: Vec<u8>,
+    #[serde(rename = "merkleRootMNList")]
+    pub merkle_root_mn_list: MerkleRootMasternodeList,
+    #[serde(rename = "merkleRootQuorums")]
+    pub merkle_root_quorums: MerkleRootQuorums,
+    /// Blocks between this block and the best ChainLocked one, from version 3 (v20)
+    #[serde(rename = "bestCLHeightDiff", default, skip_serializing_if = "Option::is_none")]
+    pub best_cl_height_diff: Option<u32>,
+    /// Signature of the best ChainLock, from version 3 (v20)
+    #[serde(rename = "bestCLSignature", default, skip_serializing_if = "Option::is_none")]
+    pub best_cl_signature: Option<BLSSignature>,
+    /// Balance of the credit pool, from version 3 (v20)
+    #[serde(
+        rename = "creditPoolBalance",
+        default,
+        with = "serde_amount::as_btc::opt",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub credit_pool_balance: Option<Amount>,
 }
 
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
 pub struct GetBestChainLockResult {
     pub blockhash: BlockHash,
     pub height: u32,
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub signature: Vec<u8>,
     pub known_block: bool,
 }
@@ -211,7 +300,7 @@ pub struct GetBlockResult {
     pub strippedsize: Option<usize>,
     pub height: usize,
     pub version: i32,
-    #[serde(default, deserialize_with = "deserialize_hex_opt")]
+    #[serde(default, with = "serde_hex::opt")]
     pub version_hex: Option<Vec<u8>>,
     pub merkleroot: dashcore::TxMerkleNode,
     pub tx: Vec<Txid>,
@@ -219,8 +308,10 @@ pub struct GetBlockResult {
     pub time: usize,
     pub mediantime: usize,
     pub nonce: u32,
-    pub bits: String,
+    #[serde(with = "serde_compact_target")]
+    pub bits: CompactTarget,
     pub difficulty: f64,
+    #[serde(with = "serde_hex")]
     pub chainwork: Vec<u8>,
     pub n_tx: usize,
     pub previousblockhash: Option<dashcore::BlockHash>,
@@ -228,6 +319,26 @@ pub struct GetBlockResult {
     pub chainlock: bool,
 }
 
+/// Chain context of a block, as returned alongside it by `get_block_with_context`
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct BlockContext {
+    pub height: u32,
+    pub confirmations: i32,
+    pub chainlock: bool,
+    pub next_block_hash: Option<dashcore::BlockHash>,
+}
+
+impl From<&GetBlockResult> for BlockContext {
+    fn from(info: &GetBlockResult) -> Self {
+        BlockContext {
+            height: info.height as u32,
+            confirmations: info.confirmations,
+            chainlock: info.chainlock,
+            next_block_hash: info.nextblockhash,
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetBlockHeaderResult {
@@ -235,7 +346,7 @@ pub struct GetBlockHeaderResult {
     pub confirmations: i32,
     pub height: usize,
     pub version: Version,
-    #[serde(default, with = "hex")]
+    #[serde(default, with = "serde_hex")]
     pub version_hex: Vec<u8>,
     #[serde(rename = "merkleroot")]
     pub merkle_root: TxMerkleNode,
@@ -243,15 +354,19 @@ pub struct GetBlockHeaderResult {
     #[serde(rename = "mediantime")]
     pub median_time: Option<usize>,
     pub nonce: u32,
-    pub bits: String,
+    #[serde(with = "serde_compact_target")]
+    pub bits: CompactTarget,
     pub difficulty: f64,
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub chainwork: Vec<u8>,
     pub n_tx: usize,
     #[serde(rename = "previousblockhash")]
     pub previous_block_hash: Option<dashcore::BlockHash>,
     #[serde(rename = "nextblockhash")]
     pub next_block_hash: Option<dashcore::BlockHash>,
+    /// Whether the block is ChainLocked
+    #[serde(default)]
+    pub chainlock: bool,
 }
 
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
@@ -468,7 +583,7 @@ impl BlockStatsFields {
             BlockStatsFields::MedianTxSize => "mediantxsize",
             BlockStatsFields::MinFee => "minfee",
             BlockStatsFields::MinFeeRate => "minfeerate",
-            BlockStatsFields::MinTxSize => "minfeerate",
+            BlockStatsFields::MinTxSize => "mintxsize",
             BlockStatsFields::Outs => "outs",
             BlockStatsFields::Subsidy => "subsidy",
             BlockStatsFields::SegWitTotalSize => "swtotal_size",
@@ -519,7 +634,7 @@ pub struct GetMiningInfoResult {
 #[serde(rename_all = "camelCase")]
 pub struct GetRawTransactionResultVinScriptSig {
     pub asm: String,
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub hex: Vec<u8>,
 }
 
@@ -535,9 +650,9 @@ pub struct GetRawTransactionResultVin {
     pub txid: Option<String>,
     pub vout: Option<u32>,
     pub script_sig: Option<GetRawTransactionResultVinScriptSig>,
-    #[serde(default, deserialize_with = "deserialize_hex_opt")]
+    #[serde(default, with = "serde_hex::opt")]
     pub coinbase: Option<Vec<u8>>,
-    #[serde(default, with = "dashcore::amount::serde::as_btc::opt")]
+    #[serde(default, with = "serde_amount::as_btc::opt")]
     pub value: Option<Amount>,
     #[serde(default)]
     pub value_sat: Option<u64>,
@@ -558,7 +673,7 @@ impl GetRawTransactionResultVin {
 #[serde(rename_all = "camelCase")]
 pub struct GetRawTransactionResultVoutScriptPubKey {
     pub asm: String,
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub hex: Vec<u8>,
     #[serde(rename = "reqSigs")]
     pub req_sigs: Option<usize>,
@@ -576,7 +691,7 @@ impl GetRawTransactionResultVoutScriptPubKey {
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetRawTransactionResultVout {
-    #[serde(with = "dashcore::amount::serde::as_btc")]
+    #[serde(with = "serde_amount::as_btc")]
     pub value: Amount,
     #[serde(rename = "valueSat")]
     pub value_sat: u64,
@@ -599,25 +714,98 @@ pub struct GetRawTransactionResult {
     pub vin: Vec<GetRawTransactionResultVin>,
     pub vout: Vec<GetRawTransactionResultVout>,
     pub extra_payload_size: Option<u32>,
-    #[serde(default, deserialize_with = "deserialize_hex_opt")]
+    #[serde(default, with = "serde_hex::opt")]
     pub extra_payload: Option<Vec<u8>>,
-    #[serde(with = "hex")]
+    /// Empty when decoded by "decoderawtransaction", which doesn't return it
+    #[serde(default, with = "serde_hex")]
     pub hex: Vec<u8>,
     pub blockhash: Option<dashcore::BlockHash>,
     pub height: Option<i32>,
     pub confirmations: Option<u32>,
     pub time: Option<usize>,
     pub blocktime: Option<usize>,
+    #[serde(default)]
     pub instantlock: bool,
-    #[serde(rename = "instantlock_internal")]
+    #[serde(default, rename = "instantlock_internal")]
     pub instantlock_internal: bool,
+    #[serde(default)]
     pub chainlock: bool,
 }
 
+impl GetRawTransactionResult {
+    /// The special transaction type of this transaction.
+    pub fn tx_type(&self) -> DashTxType {
+        self.tx_type.into()
+    }
+
+    /// Whether the transaction has at least `min_conf` confirmations.
+    pub fn is_confirmed(&self, min_conf: u32) -> bool {
+        self.confirmations.unwrap_or(0) >= min_conf
+    }
+
+    /// Whether the transaction can be considered final: either it is in a
+    /// ChainLocked block, whatever its depth, or it has at least `min_conf`
+    /// confirmations.
+    pub fn is_final(&self, min_conf: u32) -> bool {
+        self.chainlock || self.is_confirmed(min_conf)
+    }
+}
+
+/// Special transactions of a block returned by `getspecialtxes`, in the shape
+/// selected by the verbosity argument
+///
+/// Deserializing on its own can't tell the variants of an empty list apart and
+/// picks [GetSpecialTxesResult::Txids]. `RpcApi::get_special_txes` picks the
+/// variant from the verbosity instead.
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum GetSpecialTxesResult {
+    /// Verbosity 0
+    Txids(Vec<dashcore::Txid>),
+    /// Verbosity 2
+    Transactions(Vec<GetRawTransactionResult>),
+    /// Verbosity 1, the hex-encoded transactions
+    Hex(Vec<String>),
+}
+
+/// Dash special transaction types, as found in the `type` field of a transaction.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum DashTxType {
+    Classic = 0,
+    ProRegTx = 1,
+    ProUpServTx = 2,
+    ProUpRegTx = 3,
+    ProUpRevTx = 4,
+    CbTx = 5,
+    QcTx = 6,
+    MnHfTx = 7,
+    AssetLock = 8,
+    AssetUnlock = 9,
+    Unknown = 255,
+}
+
+impl From<u32> for DashTxType {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => DashTxType::Classic,
+            1 => DashTxType::ProRegTx,
+            2 => DashTxType::ProUpServTx,
+            3 => DashTxType::ProUpRegTx,
+            4 => DashTxType::ProUpRevTx,
+            5 => DashTxType::CbTx,
+            6 => DashTxType::QcTx,
+            7 => DashTxType::MnHfTx,
+            8 => DashTxType::AssetLock,
+            9 => DashTxType::AssetUnlock,
+            _ => DashTxType::Unknown,
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub struct GetBlockFilterResult {
     pub header: dashcore::FilterHash,
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub filter: Vec<u8>,
 }
 
@@ -668,22 +856,22 @@ pub enum GetTransactionResultDetailCategory {
     Orphan,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub struct GetTransactionResultDetail {
     #[serde(rename = "involvesWatchonly")]
     pub involves_watchonly: Option<bool>,
     pub address: Option<Address<NetworkUnchecked>>,
     pub category: GetTransactionResultDetailCategory,
-    #[serde(with = "dashcore::amount::serde::as_btc")]
+    #[serde(with = "serde_amount::as_btc")]
     pub amount: SignedAmount,
     pub label: Option<String>,
     pub vout: u32,
-    #[serde(default, with = "dashcore::amount::serde::as_btc::opt")]
+    #[serde(default, with = "serde_amount::as_btc::opt")]
     pub fee: Option<SignedAmount>,
     pub abandoned: Option<bool>,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub struct WalletTxInfo {
     pub confirmations: i32,
     pub blockhash: Option<BlockHash>,
@@ -698,20 +886,21 @@ pub struct WalletTxInfo {
     pub wallet_conflicts: Vec<dashcore::Txid>,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub struct GetTransactionLockedResult {
     pub height: i32,
     pub chainlock: bool,
     pub mempool: bool,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Deserialize)]
+/// Status of an asset unlock, ordered by how far it has progressed.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum AssetUnlockStatus {
-    Chainlocked,
-    Mined,
-    Mempooled,
     Unknown,
+    Mempooled,
+    Mined,
+    Chainlocked,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize)]
@@ -720,7 +909,7 @@ pub struct AssetUnlockStatusResult {
     pub status: AssetUnlockStatus,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub struct ListTransactionResult {
     #[serde(flatten)]
     pub info: WalletTxInfo,
@@ -731,7 +920,7 @@ pub struct ListTransactionResult {
     pub comment: Option<String>,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub struct ListSinceBlockResult {
     pub transactions: Vec<ListTransactionResult>,
     #[serde(default)]
@@ -739,30 +928,63 @@ pub struct ListSinceBlockResult {
     pub lastblock: BlockHash,
 }
 
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct GetTransactionResult {
+    #[serde(flatten)]
+    pub info: WalletTxInfo,
+    #[serde(with = "serde_amount::as_btc")]
+    pub amount: SignedAmount,
+    #[serde(default, with = "serde_amount::as_btc::opt")]
+    pub fee: Option<SignedAmount>,
+    #[serde(default)]
+    pub instantlock: bool,
+    #[serde(default)]
+    pub instantlock_internal: bool,
+    #[serde(default)]
+    pub chainlock: bool,
+    pub details: Vec<GetTransactionResultDetail>,
+    #[serde(with = "serde_hex")]
+    pub hex: Vec<u8>,
+}
+
+impl GetTransactionResult {
+    /// Decode the wallet transaction from its `hex` field.
+    pub fn transaction(&self) -> Result<Transaction, encode::Error> {
+        encode::deserialize(&self.hex)
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetTxOutResult {
     pub bestblock: BlockHash,
     pub confirmations: u32,
-    #[serde(with = "dashcore::amount::serde::as_btc")]
+    #[serde(with = "serde_amount::as_btc")]
     pub value: Amount,
     #[serde(rename = "scriptPubKey")]
     pub script_pub_key: GetRawTransactionResultVoutScriptPubKey,
     pub coinbase: bool,
 }
 
+impl GetTxOutResult {
+    /// The output value in duffs.
+    pub fn value_sats(&self) -> u64 {
+        self.value.to_sat()
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct ListUnspentQueryOptions {
     #[serde(
         rename = "minimumAmount",
-        with = "dashcore::amount::serde::as_btc::opt",
+        with = "serde_amount::as_btc::opt",
         skip_serializing_if = "Option::is_none"
     )]
     pub minimum_amount: Option<Amount>,
     #[serde(
         rename = "maximumAmount",
-        with = "dashcore::amount::serde::as_btc::opt",
+        with = "serde_amount::as_btc::opt",
         skip_serializing_if = "Option::is_none"
     )]
     pub maximum_amount: Option<Amount>,
@@ -770,7 +992,7 @@ pub struct ListUnspentQueryOptions {
     pub maximum_count: Option<usize>,
     #[serde(
         rename = "minimumSumAmount",
-        with = "dashcore::amount::serde::as_btc::opt",
+        with = "serde_amount::as_btc::opt",
         skip_serializing_if = "Option::is_none"
     )]
     pub minimum_sum_amount: Option<Amount>,
@@ -785,7 +1007,7 @@ pub struct ListUnspentResultEntry {
     pub script_pub_key: ScriptBuf,
     #[serde(rename = "redeemScript")]
     pub redeem_script: Option<ScriptBuf>,
-    #[serde(with = "dashcore::amount::serde::as_btc")]
+    #[serde(with = "serde_amount::as_btc")]
     pub amount: Amount,
     pub confirmations: u32,
     pub spendable: bool,
@@ -803,7 +1025,7 @@ pub struct ListReceivedByAddressResult {
     #[serde(default, rename = "involvesWatchonly")]
     pub involved_watch_only: bool,
     pub address: Address<NetworkUnchecked>,
-    #[serde(with = "dashcore::amount::serde::as_btc")]
+    #[serde(with = "serde_amount::as_btc")]
     pub amount: Amount,
     pub confirmations: u32,
     pub label: String,
@@ -812,7 +1034,7 @@ pub struct ListReceivedByAddressResult {
 
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub struct SignRawTransactionResult {
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub hex: Vec<u8>,
     pub complete: bool,
 }
@@ -877,6 +1099,16 @@ pub struct SoftforkInfo {
     pub bip9: Option<Bip9SoftforkInfo>,
 }
 
+impl SoftforkInfo {
+    /// Whether the softfork is active at block `height`.
+    ///
+    /// Both buried and BIP9 softforks report the height they activate at, BIP9
+    /// ones only once they are active.
+    pub fn is_active_at(&self, height: u32) -> bool {
+        self.height.map_or(false, |activation_height| height >= activation_height)
+    }
+}
+
 #[allow(non_camel_case_types)]
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
@@ -893,6 +1125,20 @@ pub enum ScriptPubkeyType {
     Witness_Unknown,
 }
 
+/// Models the result of "decodescript"
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct DecodeScriptResult {
+    pub asm: String,
+    #[serde(rename = "type")]
+    pub script_type: ScriptPubkeyType,
+    #[serde(rename = "reqSigs")]
+    pub req_sigs: Option<usize>,
+    #[serde(default)]
+    pub addresses: Vec<Address<NetworkUnchecked>>,
+    /// The P2SH address wrapping the script, absent if it is already P2SH
+    pub p2sh: Option<Address<NetworkUnchecked>>,
+}
+
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum GetAddressInfoResultLabelPurpose {
@@ -927,7 +1173,7 @@ pub struct GetAddressInfoResult {
     pub is_change: bool,
     pub script: Option<ScriptPubkeyType>,
     /// The redeemscript for the p2sh address.
-    #[serde(default, deserialize_with = "deserialize_hex_opt")]
+    #[serde(default, with = "serde_hex::opt")]
     pub hex: Option<Vec<u8>>,
     pub pubkeys: Option<Vec<PublicKey>>,
     pub pubkey: Option<PublicKey>,
@@ -944,7 +1190,7 @@ pub struct GetAddressInfoResult {
     #[serde(rename = "hdkeypath")]
     pub hd_key_path: Option<bip32::DerivationPath>,
     #[serde(rename = "hdmasterfingerprint")]
-    pub hd_master_fingerprint: Option<String>,
+    pub hd_master_fingerprint: Option<bip32::Fingerprint>,
     pub labels: Vec<GetAddressInfoResultLabel>,
 }
 
@@ -972,7 +1218,7 @@ pub struct GetBlockchainInfoResult {
     #[serde(rename = "initialblockdownload")]
     pub initial_block_download: bool,
     /// Total amount of work in active chain, in hexadecimal
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub chainwork: Vec<u8>,
     /// The estimated size of the block and undo files on disk
     pub size_on_disk: u64,
@@ -1036,19 +1282,42 @@ pub struct GetMempoolEntryResult {
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub struct GetMempoolEntryResultFees {
     /// Transaction fee in BTC
-    #[serde(with = "dashcore::amount::serde::as_btc")]
+    #[serde(with = "serde_amount::as_btc")]
     pub base: Amount,
     /// Transaction fee with fee deltas used for mining priority in BTC
-    #[serde(with = "dashcore::amount::serde::as_btc")]
+    #[serde(with = "serde_amount::as_btc")]
     pub modified: Amount,
     /// Modified fees (see above) of in-mempool ancestors (including this one) in BTC
-    #[serde(with = "dashcore::amount::serde::as_btc")]
+    #[serde(with = "serde_amount::as_btc")]
     pub ancestor: Amount,
     /// Modified fees (see above) of in-mempool descendants (including this one) in BTC
-    #[serde(with = "dashcore::amount::serde::as_btc")]
+    #[serde(with = "serde_amount::as_btc")]
     pub descendant: Amount,
 }
 
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct GetMempoolInfoResult {
+    /// Current number of transactions in the mempool
+    pub size: u64,
+    /// Sum of all transaction sizes
+    pub bytes: u64,
+    /// Total memory usage for the mempool
+    pub usage: u64,
+    /// Maximum memory usage for the mempool
+    #[serde(rename = "maxmempool")]
+    pub max_mempool: u64,
+    /// Minimum fee rate in DASH/kB for a transaction to be accepted, the larger
+    /// of minrelaytxfee and the minimum mempool fee
+    #[serde(rename = "mempoolminfee", with = "serde_amount::as_btc")]
+    pub mempool_min_fee: Amount,
+    /// Current minimum relay fee rate in DASH/kB
+    #[serde(rename = "minrelaytxfee", with = "serde_amount::as_btc")]
+    pub min_relay_tx_fee: Amount,
+    /// Current number of transactions that haven't passed initial broadcast yet
+    #[serde(rename = "unbroadcastcount")]
+    pub unbroadcast_count: u64,
+}
+
 impl<'a> serde::Serialize for ImportMultiRequestScriptPubkey<'a> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1105,6 +1374,25 @@ pub struct ImportMultiRequest<'a> {
     pub keypool: Option<bool>,
 }
 
+/// A import request for importdescriptors.
+///
+/// Note: unlike in dashcored, `timestamp` defaults to 0.
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize)]
+pub struct ImportDescriptorRequest<'a> {
+    pub desc: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range: Option<(usize, usize)>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_index: Option<usize>,
+    pub timestamp: ImportMultiRescanSince,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub internal: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<&'a str>,
+}
+
 #[derive(Clone, PartialEq, Eq, Debug, Default, Deserialize, Serialize)]
 pub struct ImportMultiOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1193,7 +1481,7 @@ pub struct ImportMultiResultError {
 
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub struct ImportMultiResultImport {
-    #[serde(rename = "scriptPubKey")]
+    #[serde(default, rename = "scriptPubKey", with = "serde_hex::opt")]
     pub script_pub_key: Option<Vec<u8>>,
     pub address: Option<Address<NetworkUnchecked>>,
     pub timestamp: ImportMultiRescanSince,
@@ -1214,6 +1502,22 @@ pub struct ImportMultiResult {
     pub error: Option<ImportMultiResultError>,
 }
 
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct ListDescriptorsResult {
+    pub wallet_name: String,
+    pub descriptors: Vec<ListDescriptorsResultEntry>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct ListDescriptorsResultEntry {
+    pub desc: String,
+    pub timestamp: ImportMultiRescanSince,
+    pub active: bool,
+    pub internal: Option<bool>,
+    pub range: Option<(usize, usize)>,
+    pub next: Option<usize>,
+}
+
 /// Progress toward rejecting pre-softfork blocks
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub struct RejectStatus {
@@ -1238,8 +1542,7 @@ pub struct GetPeerInfoResult {
     /// Added in Bitcoin Core v0.21
     pub network: Option<GetPeerInfoResultNetwork>,
     /// The services offered
-    // TODO: use a type for services
-    pub services: String,
+    pub services: ServiceFlags,
     /// Whether peer has asked us to relay transactions to it
     pub relaytxes: bool,
     /// The time in seconds since epoch (Jan 1 1970 GMT) of the last send
@@ -1290,7 +1593,7 @@ pub struct GetPeerInfoResult {
     /// Whether the peer is whitelisted
     /// Deprecated in Bitcoin Core v0.21
     pub whitelisted: Option<bool>,
-    #[serde(rename = "minfeefilter", default, with = "dashcore::amount::serde::as_btc::opt")]
+    #[serde(rename = "minfeefilter", default, with = "serde_amount::as_btc::opt")]
     pub min_fee_filter: Option<Amount>,
     /// The total bytes sent aggregated by message type
     pub bytessent_per_msg: HashMap<String, u64>,
@@ -1377,7 +1680,7 @@ pub struct EstimateSmartFeeResult {
         default,
         rename = "feerate",
         skip_serializing_if = "Option::is_none",
-        with = "dashcore::amount::serde::as_btc::opt"
+        with = "serde_amount::as_btc::opt"
     )]
     pub fee_rate: Option<Amount>,
     /// Errors encountered during processing.
@@ -1386,6 +1689,35 @@ pub struct EstimateSmartFeeResult {
     pub blocks: i64,
 }
 
+/// A fee rate, expressed like Dash Core as an amount per 1000 bytes.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct FeeRate {
+    per_kb: Amount,
+}
+
+impl FeeRate {
+    pub fn from_per_kb(per_kb: Amount) -> FeeRate {
+        FeeRate {
+            per_kb,
+        }
+    }
+
+    pub fn per_kb(&self) -> Amount {
+        self.per_kb
+    }
+
+    /// The fee for a transaction of `size` bytes, rounded like Dash Core: down,
+    /// but to at least one duff for a non-zero rate.
+    pub fn fee_for_size(&self, size: usize) -> Amount {
+        let fee = self.per_kb.to_sat().saturating_mul(size as u64) / 1000;
+        if fee == 0 && size != 0 && self.per_kb.to_sat() != 0 {
+            Amount::from_sat(1)
+        } else {
+            Amount::from_sat(fee)
+        }
+    }
+}
+
 /// Models the result of "waitfornewblock", and "waitforblock"
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub struct BlockRef {
@@ -1486,7 +1818,7 @@ pub struct GetBlockTemplateResult {
     // TODO figure out what is the data is represented to coinbasetxn
     // pub coinbasetxn:
     /// The number which valid hashes must be less than, in big-endian
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub target: Vec<u8>,
     /// The minimum timestamp appropriate for the next block time. Expressed as
     /// UNIX timestamp.
@@ -1498,7 +1830,7 @@ pub struct GetBlockTemplateResult {
     // TODO figure out what is the data is represented to value
     // pub value:
     /// A range of valid nonces
-    #[serde(with = "hex", rename = "noncerange")]
+    #[serde(with = "serde_hex", rename = "noncerange")]
     pub nonce_range: Vec<u8>,
     /// Block sigops limit
     #[serde(rename = "sigoplimit")]
@@ -1511,11 +1843,11 @@ pub struct GetBlockTemplateResult {
     /// the mintime/maxtime rules. Expressed as UNIX timestamp.
     #[serde(rename = "curtime")]
     pub current_time: u64,
-    /// The compressed difficulty in hexadecimal
-    #[serde(with = "hex")]
-    pub bits: Vec<u8>,
-    #[serde(with = "hex", rename = "previousbits")]
-    pub previous_bits: Vec<u8>,
+    /// The compressed difficulty
+    #[serde(with = "serde_compact_target")]
+    pub bits: CompactTarget,
+    #[serde(with = "serde_compact_target", rename = "previousbits")]
+    pub previous_bits: CompactTarget,
     /// The height of the block we will be mining: `current height + 1`
     pub height: u64,
     pub masternode: Vec<GetBlockTemplateResultPayeeInfo>,
@@ -1533,9 +1865,14 @@ pub struct GetBlockTemplateResult {
 /// Models a single transaction entry in the result of "getblocktemplate"
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub struct GetBlockTemplateResultTransaction {
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub data: Vec<u8>,
-    pub hash: BlockHash,
+    /// The transaction id
+    pub hash: Txid,
+    /// The transaction id, only reported by nodes that list it separately from
+    /// `hash`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub txid: Option<Txid>,
     /// Transactions that must be in present in the final block if this one is.
     /// Indexed by a 1-based index in the `GetBlockTemplateResult.transactions`
     /// list
@@ -1593,7 +1930,7 @@ pub enum GetBlockTemplateResulMutations {
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub struct WalletCreateFundedPsbtResult {
     pub psbt: String,
-    #[serde(with = "dashcore::amount::serde::as_btc")]
+    #[serde(with = "serde_amount::as_btc")]
     pub fee: Amount,
     #[serde(rename = "changepos")]
     pub change_position: i32,
@@ -1624,7 +1961,7 @@ pub struct WalletCreateFundedPsbtOptions {
     #[serde(
         rename = "feeRate",
         skip_serializing_if = "Option::is_none",
-        with = "dashcore::amount::serde::as_btc::opt"
+        with = "serde_amount::as_btc::opt"
     )]
     pub fee_rate: Option<Amount>,
     #[serde(rename = "subtractFeeFromOutputs", skip_serializing_if = "Vec::is_empty")]
@@ -1643,6 +1980,118 @@ pub struct FinalizePsbtResult {
     pub complete: bool,
 }
 
+/// Models the result of "analyzepsbt"
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct AnalyzePsbtResult {
+    #[serde(default)]
+    pub inputs: Vec<AnalyzePsbtResultInput>,
+    /// Estimated size of the final signed transaction
+    #[serde(alias = "estimated_size")]
+    pub estimated_vsize: Option<u32>,
+    /// Estimated fee rate of the final signed transaction, per kB
+    #[serde(default, with = "serde_amount::as_btc::opt")]
+    pub estimated_feerate: Option<Amount>,
+    /// The transaction fee paid, once all UTXOs are known
+    #[serde(default, with = "serde_amount::as_btc::opt")]
+    pub fee: Option<Amount>,
+    /// Role of the next person that needs to process the PSBT
+    pub next: String,
+    pub error: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct AnalyzePsbtResultInput {
+    pub has_utxo: bool,
+    pub is_final: bool,
+    /// What is missing to finalize the input
+    pub missing: Option<AnalyzePsbtResultInputMissing>,
+    /// Role of the next person that needs to process this input
+    pub next: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct AnalyzePsbtResultInputMissing {
+    /// Hex-encoded key ids of the public keys whose BIP 32 derivation paths are missing
+    #[serde(default)]
+    pub pubkeys: Vec<String>,
+    /// Hex-encoded key ids of the public keys whose signatures are missing
+    #[serde(default)]
+    pub signatures: Vec<String>,
+    /// Hash of the missing redeem script
+    #[serde(rename = "redeemscript")]
+    pub redeem_script: Option<String>,
+}
+
+/// Models the result of "decodepsbt"
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct DecodePsbtResult {
+    /// The unsigned transaction
+    pub tx: GetRawTransactionResult,
+    pub inputs: Vec<DecodePsbtResultInput>,
+    pub outputs: Vec<DecodePsbtResultOutput>,
+    /// The transaction fee paid, if all UTXOs are known
+    #[serde(default, with = "serde_amount::as_btc::opt")]
+    pub fee: Option<Amount>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct DecodePsbtResultInput {
+    /// The transaction the input spends from
+    pub non_witness_utxo: Option<GetRawTransactionResult>,
+    pub witness_utxo: Option<DecodePsbtResultWitnessUtxo>,
+    /// Signatures collected so far, keyed by hex-encoded public key
+    #[serde(default)]
+    pub partial_signatures: HashMap<String, String>,
+    pub sighash: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct DecodePsbtResultWitnessUtxo {
+    #[serde(with = "serde_amount::as_btc")]
+    pub amount: Amount,
+    #[serde(rename = "scriptPubKey")]
+    pub script_pub_key: GetRawTransactionResultVoutScriptPubKey,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct DecodePsbtResultOutput {
+    pub redeem_script: Option<GetRawTransactionResultVoutScriptPubKey>,
+    pub witness_script: Option<GetRawTransactionResultVoutScriptPubKey>,
+    #[serde(default)]
+    pub bip32_derivs: Vec<DecodePsbtResultBip32Deriv>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct DecodePsbtResultBip32Deriv {
+    pub pubkey: PublicKey,
+    pub master_fingerprint: bip32::Fingerprint,
+    pub path: bip32::DerivationPath,
+}
+
+/// Models the result of "getchaintxstats"
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GetChainTxStatsResult {
+    /// The timestamp for the final block in the window
+    pub time: u64,
+    /// The total number of transactions in the chain up to that point
+    #[serde(rename = "txcount")]
+    pub tx_count: u64,
+    /// The hash of the final block in the window
+    pub window_final_block_hash: BlockHash,
+    /// The height of the final block in the window
+    pub window_final_block_height: u32,
+    /// Size of the window in number of blocks
+    pub window_block_count: u32,
+    /// The number of transactions in the window, omitted if window_block_count is 0
+    pub window_tx_count: Option<u64>,
+    /// The elapsed time in the window in seconds, omitted if window_block_count is 0
+    pub window_interval: Option<u64>,
+    /// The average rate of transactions per second in the window, only present
+    /// if window_interval is greater than 0
+    #[serde(rename = "txrate")]
+    pub tx_rate: Option<f64>,
+}
+
 /// Models the result of "getchaintips"
 pub type GetChainTipsResult = Vec<GetChainTipsResultTip>;
 
@@ -1737,37 +2186,57 @@ pub struct FundRawTransactionOptions {
     pub include_watching: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lock_unspents: Option<bool>,
-    #[serde(
-        with = "dashcore::amount::serde::as_btc::opt",
-        skip_serializing_if = "Option::is_none"
-    )]
+    #[serde(with = "serde_amount::as_btc::opt", skip_serializing_if = "Option::is_none")]
     pub fee_rate: Option<Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subtract_fee_from_outputs: Option<Vec<u32>>,
 }
 
-#[derive(Deserialize, Clone, PartialEq, Eq, Debug)]
+#[derive(Deserialize, Serialize, Clone, PartialEq, Eq, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct FundRawTransactionResult {
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub hex: Vec<u8>,
-    #[serde(with = "dashcore::amount::serde::as_btc")]
+    #[serde(with = "serde_amount::as_btc")]
     pub fee: Amount,
     #[serde(rename = "changepos")]
     pub change_position: i32,
 }
 
-#[derive(Deserialize, Clone, PartialEq, Eq, Debug)]
+#[derive(Serialize, Clone, PartialEq, Eq, Debug, Default)]
+pub struct BumpFeeOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conf_target: Option<u16>,
+    /// Fee rate per kB.
+    #[serde(with = "serde_amount::as_btc::opt", skip_serializing_if = "Option::is_none")]
+    pub fee_rate: Option<Amount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replaceable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimate_mode: Option<EstimateMode>,
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq, Eq, Debug)]
+pub struct BumpFeeResult {
+    pub txid: Option<dashcore::Txid>,
+    #[serde(with = "serde_amount::as_btc")]
+    pub origfee: Amount,
+    #[serde(with = "serde_amount::as_btc")]
+    pub fee: Amount,
+    pub errors: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq, Eq, Debug)]
 pub struct GetBalancesResultEntry {
-    #[serde(with = "dashcore::amount::serde::as_btc")]
+    #[serde(with = "serde_amount::as_btc")]
     pub trusted: Amount,
-    #[serde(with = "dashcore::amount::serde::as_btc")]
+    #[serde(with = "serde_amount::as_btc")]
     pub untrusted_pending: Amount,
-    #[serde(with = "dashcore::amount::serde::as_btc")]
+    #[serde(with = "serde_amount::as_btc")]
     pub immature: Amount,
 }
 
-#[derive(Deserialize, Clone, PartialEq, Eq, Debug)]
+#[derive(Deserialize, Serialize, Clone, PartialEq, Eq, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct GetBalancesResult {
     pub mine: GetBalancesResultEntry,
@@ -1789,11 +2258,7 @@ pub struct SignRawTransactionInput {
     pub script_pub_key: ScriptBuf,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub redeem_script: Option<ScriptBuf>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "dashcore::amount::serde::as_btc::opt"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "serde_amount::as_btc::opt")]
     pub amount: Option<Amount>,
 }
 
@@ -1816,7 +2281,7 @@ pub struct GetTxOutSetInfoResult {
     /// The estimated size of the chainstate on disk
     pub disk_size: u64,
     /// The total amount
-    #[serde(with = "dashcore::amount::serde::as_btc")]
+    #[serde(with = "serde_amount::as_btc")]
     pub total_amount: Amount,
 }
 
@@ -1893,10 +2358,21 @@ pub struct ScanTxOutResult {
     #[serde(rename = "bestblock")]
     pub best_block_hash: Option<BlockHash>,
     pub unspents: Vec<Utxo>,
-    #[serde(with = "dashcore::amount::serde::as_btc")]
+    #[serde(with = "serde_amount::as_btc")]
     pub total_amount: Amount,
 }
 
+impl ScanTxOutResult {
+    /// The total amount of the found outputs per descriptor.
+    pub fn by_descriptor(&self) -> HashMap<String, Amount> {
+        let mut totals = HashMap::new();
+        for utxo in &self.unspents {
+            *totals.entry(utxo.descriptor.clone()).or_insert(Amount::ZERO) += utxo.amount;
+        }
+        totals
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Utxo {
@@ -1905,7 +2381,7 @@ pub struct Utxo {
     pub script_pub_key: ScriptBuf,
     #[serde(rename = "desc")]
     pub descriptor: String,
-    #[serde(with = "dashcore::amount::serde::as_btc")]
+    #[serde(with = "serde_amount::as_btc")]
     pub amount: Amount,
     pub height: u64,
 }
@@ -1922,39 +2398,130 @@ impl<'a> serde::Serialize for PubKeyOrAddress<'a> {
     }
 }
 
-// --------------------------- Masternode -------------------------------
+// --------------------------- Address index -------------------------------
 
-#[derive(Clone, PartialEq, Eq, Debug)]
-pub enum ProTxListType {
-    Registered,
-    Valid,
-    Wallet,
+/// Models the result of "getaddressbalance"
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct GetAddressBalanceResult {
+    /// The current balance in duffs
+    #[serde(with = "dashcore::amount::serde::as_sat")]
+    pub balance: Amount,
+    /// The total number of duffs received, including change
+    #[serde(with = "dashcore::amount::serde::as_sat")]
+    pub received: Amount,
 }
 
-impl Serialize for ProTxListType {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        match self {
-            ProTxListType::Registered => serializer.serialize_str("registered"),
-            ProTxListType::Valid => serializer.serialize_str("valid"),
-            ProTxListType::Wallet => serializer.serialize_str("wallet"),
-        }
-    }
+/// Models a single entry in the result of "getaddressdeltas"
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct AddressDelta {
+    /// The balance change in duffs, negative for spends
+    #[serde(with = "dashcore::amount::serde::as_sat")]
+    pub satoshis: SignedAmount,
+    pub txid: Txid,
+    /// The input or output index within the transaction
+    pub index: u32,
+    /// The index of the transaction within its block
+    pub blockindex: u32,
+    pub height: u32,
+    pub address: Address<NetworkUnchecked>,
 }
 
+/// Models a single entry in the result of "getaddressmempool"
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
-pub struct GetMasternodeCountResult {
-    pub total: u32,
-    pub enabled: u32,
+pub struct AddressMempoolDelta {
+    pub address: Address<NetworkUnchecked>,
+    pub txid: Txid,
+    pub index: u32,
+    /// The balance change in duffs, negative for spends
+    #[serde(with = "dashcore::amount::serde::as_sat")]
+    pub satoshis: SignedAmount,
+    /// The time the transaction entered the mempool (seconds)
+    pub timestamp: u64,
+    /// The previous txid, only present for spends
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prevtxid: Option<Txid>,
+    /// The previous transaction output index, only present for spends
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prevout: Option<u32>,
 }
 
-#[serde_as]
+/// Models a single entry in the result of "getaddressutxos"
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
-pub struct Masternode {
-    #[serde(rename = "proTxHash")]
-    pub pro_tx_hash: ProTxHash,
+#[serde(rename_all = "camelCase")]
+pub struct AddressUtxo {
+    pub address: Address<NetworkUnchecked>,
+    pub txid: Txid,
+    pub output_index: u32,
+    pub script: ScriptBuf,
+    #[serde(with = "dashcore::amount::serde::as_sat")]
+    pub satoshis: Amount,
+    pub height: u32,
+}
+
+/// Models the result of "getaddressutxos" with `chainInfo` set
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct GetAddressUtxosChainInfoResult {
+    pub utxos: Vec<AddressUtxo>,
+    /// The hash of the chain tip the outputs were computed at
+    pub hash: BlockHash,
+    /// The height of the chain tip the outputs were computed at
+    pub height: u32,
+}
+
+/// Models the result of "getspentinfo"
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct SpentInfoResult {
+    /// The spending transaction
+    pub txid: Txid,
+    /// The spending input index
+    pub index: u32,
+    /// The height of the block containing the spending transaction
+    pub height: u32,
+}
+
+/// Options for "getblockhashes"
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBlockHashesOptions {
+    /// Only return blocks in the main chain
+    pub no_orphans: bool,
+    /// Also return the logical timestamp of each block
+    pub logical_times: bool,
+}
+
+// --------------------------- Masternode -------------------------------
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ProTxListType {
+    Registered,
+    Valid,
+    Wallet,
+}
+
+impl Serialize for ProTxListType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ProTxListType::Registered => serializer.serialize_str("registered"),
+            ProTxListType::Valid => serializer.serialize_str("valid"),
+            ProTxListType::Wallet => serializer.serialize_str("wallet"),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct GetMasternodeCountResult {
+    pub total: u32,
+    pub enabled: u32,
+}
+
+#[serde_as]
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct Masternode {
+    #[serde(rename = "proTxHash")]
+    pub pro_tx_hash: ProTxHash,
     #[serde_as(as = "DisplayFromStr")]
     pub address: SocketAddr,
     #[serde_as(as = "Bytes")]
@@ -1985,11 +2552,43 @@ pub struct Masternode {
     #[serde_as(as = "Bytes")]
     #[serde(rename = "collateraladdress")]
     pub collateral_address: Vec<u8>,
-    #[serde_as(as = "Bytes")]
-    #[serde(rename = "pubkeyoperator")]
+    #[serde(rename = "pubkeyoperator", with = "serde_hex")]
     pub pubkey_operator: Vec<u8>,
 }
 
+impl Masternode {
+    /// The operator BLS public key.
+    pub fn operator_pubkey(&self) -> Result<BLSPublicKey, encode::Error> {
+        bls_public_key(&self.pubkey_operator)
+    }
+
+    /// The payout address, checked against `network`.
+    pub fn payee_address(&self, network: Network) -> Result<Address, address::Error> {
+        masternode_address(&self.payee, network)
+    }
+
+    /// The owner address, checked against `network`.
+    pub fn owner_address(&self, network: Network) -> Result<Address, address::Error> {
+        masternode_address(&self.owner_address, network)
+    }
+
+    /// The voting address, checked against `network`.
+    pub fn voting_address(&self, network: Network) -> Result<Address, address::Error> {
+        masternode_address(&self.voting_address, network)
+    }
+
+    /// The collateral address, checked against `network`.
+    pub fn collateral_address(&self, network: Network) -> Result<Address, address::Error> {
+        masternode_address(&self.collateral_address, network)
+    }
+}
+
+/// `masternode list` returns addresses as base58 strings, which are kept
+/// as their raw bytes in [Masternode].
+fn masternode_address(bytes: &[u8], network: Network) -> Result<Address, address::Error> {
+    String::from_utf8_lossy(bytes).parse::<Address<NetworkUnchecked>>()?.require_network(network)
+}
+
 // TODO: clean up the new structure + test deserialization
 
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize, Encode, Decode)]
@@ -1999,12 +2598,14 @@ pub enum MasternodeType {
 }
 
 #[serde_as]
-#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize, Encode, Decode)]
 #[serde(rename_all = "camelCase")]
 pub struct MasternodeListItem {
-    #[serde(rename = "type")]
+    #[serde(rename = "type", deserialize_with = "deserialize_integer_or_string")]
     pub node_type: MasternodeType,
+    #[bincode(with_serde)]
     pub pro_tx_hash: ProTxHash,
+    #[bincode(with_serde)]
     pub collateral_hash: Txid,
     pub collateral_index: u32,
     #[serde(deserialize_with = "deserialize_address")]
@@ -2057,9 +2658,12 @@ pub struct GetMasternodePaymentsResult {
 }
 
 #[serde_as]
-#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize, Encode, Decode)]
 #[serde(rename_all = "camelCase")]
 pub struct DMNState {
+    /// The state version: 1 for legacy BLS operator keys, 2 for basic ones.
+    #[serde(default)]
+    pub version: Option<u16>,
     #[serde_as(as = "DisplayFromStr")]
     pub service: SocketAddr,
     pub registered_height: u32,
@@ -2074,7 +2678,7 @@ pub struct DMNState {
     pub voting_address: [u8; 20],
     #[serde(deserialize_with = "deserialize_address")]
     pub payout_address: [u8; 20],
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub pub_key_operator: Vec<u8>,
     #[serde(default, deserialize_with = "deserialize_address_optional")]
     pub operator_payout_address: Option<[u8; 20]>,
@@ -2090,7 +2694,7 @@ pub struct DMNState {
     pub platform_http_port: Option<u32>,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Encode, Decode)]
 #[serde(try_from = "DMNStateDiffIntermediate")]
 pub struct DMNStateDiff {
     pub service: Option<SocketAddr>,
@@ -2199,6 +2803,21 @@ impl TryFrom<DMNStateDiffIntermediate> for DMNStateDiff {
 }
 
 impl DMNState {
+    /// The operator BLS public key.
+    pub fn operator_pubkey(&self) -> Result<BLSPublicKey, encode::Error> {
+        bls_public_key(&self.pub_key_operator)
+    }
+
+    /// Whether the operator key is in the legacy BLS serialization.
+    pub fn is_legacy_bls(&self) -> bool {
+        self.version == Some(1)
+    }
+
+    /// The typed reason the masternode was revoked for; `NotSpecified` if it wasn't revoked.
+    pub fn revocation_reason(&self) -> ProTxRevokeReason {
+        ProTxRevokeReason::from(self.revocation_reason)
+    }
+
     pub fn compare_to_older_dmn_state(&self, older: &DMNState) -> Option<DMNStateDiff> {
         older.compare_to_newer_dmn_state(self)
     }
@@ -2376,7 +2995,7 @@ pub struct MasternodeStatus {
     pub pro_tx_hash: ProTxHash,
     #[serde(rename = "type")]
     pub node_type: String,
-    #[serde(rename = "collateralHash", with = "hex")]
+    #[serde(rename = "collateralHash", with = "serde_hex")]
     pub collateral_hash: Vec<u8>,
     #[serde(rename = "collateralIndex")]
     pub collateral_index: u32,
@@ -2441,14 +3060,107 @@ where
     })
 }
 
+// --------------------------- Governance -------------------------------
+
+/// Governance parameters and superblock schedule returned by `getgovernanceinfo`
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct GetGovernanceInfoResult {
+    /// Absolute minimum number of votes needed to trigger a governance action
+    #[serde(rename = "governanceminquorum")]
+    pub governance_min_quorum: u32,
+    /// Collateral burnt to submit a proposal
+    #[serde(rename = "proposalfee", with = "serde_amount::as_btc")]
+    pub proposal_fee: Amount,
+    /// Number of blocks between superblocks
+    #[serde(rename = "superblockcycle")]
+    pub superblock_cycle: u32,
+    /// Number of blocks before a superblock in which triggers are created
+    #[serde(rename = "superblockmaturitywindow")]
+    pub superblock_maturity_window: u32,
+    #[serde(rename = "lastsuperblock")]
+    pub last_superblock: u32,
+    #[serde(rename = "nextsuperblock")]
+    pub next_superblock: u32,
+    /// Number of absolute yes votes a proposal needs to be funded
+    #[serde(rename = "fundingthreshold")]
+    pub funding_threshold: u32,
+}
+
+/// A governance object (proposal or trigger) returned by `gobject list` and
+/// `gobject get`
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GovernanceObject {
+    /// Hash of the governance object
+    pub hash: sha256d::Hash,
+    /// Serialized object data
+    pub data_hex: String,
+    /// Object data, decoded from the JSON string Core returns
+    #[serde(deserialize_with = "deserialize_json_string")]
+    pub data_string: Value,
+    /// Transaction paying the object's collateral
+    pub collateral_hash: Txid,
+    /// 1 for proposals, 2 for triggers
+    pub object_type: u32,
+    pub creation_time: u64,
+    pub absolute_yes_count: i64,
+    pub yes_count: u32,
+    pub no_count: u32,
+    pub abstain_count: u32,
+}
+
+/// Deserializes a string that holds a JSON document into its value
+fn deserialize_json_string<'de, D>(deserializer: D) -> Result<Value, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    serde_json::from_str(&s).map_err(de::Error::custom)
+}
+
+// --------------------------- CoinJoin -------------------------------
+
+/// CoinJoin mixing status of a wallet returned by `getcoinjoininfo`
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct CoinJoinInfoResult {
+    pub enabled: bool,
+    pub multisession: bool,
+    pub max_sessions: u32,
+    pub max_rounds: u32,
+    /// Target amount to keep mixed
+    #[serde(with = "serde_amount::as_btc")]
+    pub max_amount: Amount,
+    pub denoms_goal: u32,
+    pub denoms_hardcap: u32,
+    pub queue_size: u32,
+    /// Whether mixing is in progress
+    pub running: bool,
+    pub sessions: Vec<CoinJoinSession>,
+}
+
+/// A CoinJoin mixing session with a masternode
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct CoinJoinSession {
+    #[serde(rename = "protxhash")]
+    pub pro_tx_hash: ProTxHash,
+    /// Collateral of the mixing masternode
+    #[serde(deserialize_with = "deserialize_outpoint")]
+    pub outpoint: dashcore::OutPoint,
+    pub service: String,
+    #[serde(with = "serde_amount::as_btc")]
+    pub denomination: Amount,
+    pub state: String,
+    pub entries_count: u32,
+}
+
 // --------------------------- BLS -------------------------------
 
 #[serde_as]
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub struct BLS {
-    #[serde_as(as = "Bytes")]
+    #[serde(with = "serde_hex")]
     pub secret: Vec<u8>,
-    #[serde_as(as = "Bytes")]
+    #[serde(with = "serde_hex")]
     pub public: Vec<u8>,
 }
 
@@ -2555,8 +3267,8 @@ pub struct ExtendedQuorumDetails {
     #[bincode(with_serde)]
     pub mined_block_hash: BlockHash,
     pub num_valid_members: u32,
-    #[serde(deserialize_with = "deserialize_f32")]
-    pub health_ratio: f32,
+    #[serde(deserialize_with = "deserialize_f64")]
+    pub health_ratio: f64,
 }
 
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
@@ -2597,13 +3309,47 @@ pub struct ExtendedQuorumListResultIntermediate {
     pub quorums_by_type: HashMap<QuorumType, Vec<HashMap<QuorumHash, ExtendedQuorumDetails>>>,
 }
 
+/// Accepts both the `u8` representation `QuorumType` is serialized to and the
+/// string name Dash Core uses in results, so that serialized values (including
+/// map keys) can be read back.
 impl<'de> Deserialize<'de> for QuorumType {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let s = String::deserialize(deserializer)?;
-        Ok(QuorumType::from(s.as_str()))
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = QuorumType;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "quorum type as integer or name")
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(u32::try_from(value).map_or(QuorumType::UNKNOWN, QuorumType::from))
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(u32::try_from(value).map_or(QuorumType::UNKNOWN, QuorumType::from))
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(match value.parse::<u32>() {
+                    Ok(n) => QuorumType::from(n),
+                    Err(_) => QuorumType::from(value),
+                })
+            }
+        }
+        deserializer.deserialize_any(Visitor)
     }
 }
 
@@ -2633,30 +3379,60 @@ pub struct QuorumListResultInternal<T> {
 #[serde(rename_all = "camelCase")]
 pub struct QuorumMember {
     pub pro_tx_hash: ProTxHash,
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub pub_key_operator: Vec<u8>,
     pub valid: bool,
-    #[serde(default, deserialize_with = "deserialize_hex_opt")]
+    #[serde(default, with = "serde_hex::opt")]
     pub pub_key_share: Option<Vec<u8>>,
 }
 
+impl QuorumMember {
+    /// The operator BLS public key.
+    pub fn operator_pubkey(&self) -> Result<BLSPublicKey, encode::Error> {
+        bls_public_key(&self.pub_key_operator)
+    }
+
+    /// The member's public key share, present when the member is valid.
+    pub fn pub_key_share(&self) -> Option<Result<BLSPublicKey, encode::Error>> {
+        self.pub_key_share.as_deref().map(bls_public_key)
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct QuorumInfoResult {
     pub height: u32,
-    #[serde(rename = "type", deserialize_with = "deserialize_quorum_type")]
+    #[serde(rename = "type", deserialize_with = "deserialize_integer_or_string")]
     pub quorum_type: QuorumType,
     pub quorum_hash: QuorumHash,
     pub quorum_index: u32,
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub mined_block: Vec<u8>,
     pub members: Vec<QuorumMember>,
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub quorum_public_key: Vec<u8>,
-    #[serde(default, deserialize_with = "deserialize_hex_opt")]
+    #[serde(default, with = "serde_hex::opt")]
     pub secret_key_share: Option<Vec<u8>>,
 }
 
+impl QuorumInfoResult {
+    /// The quorum BLS public key.
+    pub fn quorum_public_key(&self) -> Result<BLSPublicKey, encode::Error> {
+        bls_public_key(&self.quorum_public_key)
+    }
+
+    /// The node's 32-byte secret key share, present when it was requested and
+    /// the node is a member of the quorum.
+    pub fn secret_key_share(&self) -> Option<Result<[u8; 32], encode::Error>> {
+        self.secret_key_share.as_deref().map(|bytes| {
+            bytes.try_into().map_err(|_| encode::Error::InvalidVectorSize {
+                expected: 32,
+                actual: bytes.len(),
+            })
+        })
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct QuorumSessionStatusMember {
@@ -2676,7 +3452,7 @@ pub enum MemberDetail {
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct QuorumSessionStatus {
-    #[serde(deserialize_with = "deserialize_quorum_type")]
+    #[serde(deserialize_with = "deserialize_integer_or_string")]
     pub llmq_type: QuorumType,
     pub quorum_hash: QuorumHash,
     pub quorum_height: u32,
@@ -2698,7 +3474,7 @@ pub struct QuorumSessionStatus {
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct QuorumSession {
-    #[serde(deserialize_with = "deserialize_quorum_type")]
+    #[serde(deserialize_with = "deserialize_integer_or_string")]
     pub llmq_type: QuorumType,
     pub quorum_index: u32,
     pub status: QuorumSessionStatus,
@@ -2719,7 +3495,7 @@ pub struct QuorumConnectionInfo {
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct QuorumConnection {
-    #[serde(deserialize_with = "deserialize_quorum_type")]
+    #[serde(deserialize_with = "deserialize_integer_or_string")]
     pub llmq_type: QuorumType,
     pub quorum_index: u32,
     pub p_quorum_base_block_index: Option<u32>,
@@ -2733,30 +3509,30 @@ pub struct QuorumConnection {
 #[serde(rename_all = "camelCase")]
 pub struct QuorumMinableCommitments {
     pub version: u8,
-    #[serde(deserialize_with = "deserialize_quorum_type")]
+    #[serde(deserialize_with = "deserialize_integer_or_string")]
     pub llmq_type: QuorumType,
     pub quorum_hash: QuorumHash,
     pub quorum_index: u32,
     pub signers_count: u32,
-    #[serde_as(as = "Bytes")]
+    #[serde(with = "serde_hex")]
     pub signers: Vec<u8>,
     pub valid_members_count: u32,
-    #[serde_as(as = "Bytes")]
+    #[serde(with = "serde_hex")]
     pub valid_members: Vec<u8>,
-    #[serde_as(as = "Bytes")]
+    #[serde(with = "serde_hex")]
     pub quorum_public_key: Vec<u8>,
-    #[serde_as(as = "Bytes")]
+    #[serde(with = "serde_hex")]
     pub quorum_vvec_hash: Vec<u8>,
-    #[serde_as(as = "Bytes")]
+    #[serde(with = "serde_hex")]
     pub quorum_sig: Vec<u8>,
-    #[serde_as(as = "Bytes")]
+    #[serde(with = "serde_hex")]
     pub members_sig: Vec<u8>,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct QuorumItemDeleted {
-    #[serde(deserialize_with = "deserialize_quorum_type")]
+    #[serde(deserialize_with = "deserialize_integer_or_string")]
     pub llmq_type: QuorumType,
     pub quorum_hash: QuorumHash,
 }
@@ -2774,17 +3550,17 @@ pub struct QuorumDKGStatus {
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct QuorumSignature {
-    #[serde(deserialize_with = "deserialize_quorum_type")]
+    #[serde(deserialize_with = "deserialize_integer_or_string")]
     pub llmq_type: QuorumType,
     pub quorum_hash: QuorumHash,
     pub quorum_member: Option<u8>,
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub id: Vec<u8>,
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub msg_hash: Vec<u8>,
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub sign_hash: Vec<u8>,
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub signature: Vec<u8>,
 }
 
@@ -2799,12 +3575,12 @@ pub enum QuorumSignResult {
 #[serde(rename_all = "camelCase")]
 pub struct QuorumMemberOf {
     pub height: u32,
-    #[serde(rename = "type", deserialize_with = "deserialize_quorum_type")]
+    #[serde(rename = "type", deserialize_with = "deserialize_integer_or_string")]
     pub quorum_type: QuorumType,
     pub quorum_hash: QuorumHash,
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub mined_block: Vec<u8>,
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub quorum_public_key: Vec<u8>,
     pub is_valid_member: bool,
     pub member_index: u32,
@@ -2825,13 +3601,13 @@ pub struct QuorumSnapshot {
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct QuorumMasternodeListItem {
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub pro_reg_tx_hash: Vec<u8>,
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub confirmed_hash: Vec<u8>,
     #[serde_as(as = "DisplayFromStr")]
     pub service: SocketAddr,
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub pub_key_operator: Vec<u8>,
     #[serde_as(as = "Bytes")]
     pub voting_address: Vec<u8>,
@@ -2844,18 +3620,18 @@ pub struct QuorumMasternodeListItem {
 pub struct MasternodeDiff {
     pub base_block_hash: dashcore::BlockHash,
     pub block_hash: dashcore::BlockHash,
-    #[serde_as(as = "Bytes")]
+    #[serde(with = "serde_hex")]
     pub cb_tx_merkle_tree: Vec<u8>,
-    #[serde_as(as = "Bytes")]
+    #[serde(with = "serde_hex")]
     pub cb_tx: Vec<u8>,
     #[serde(rename = "deletedMNs")]
     pub deleted_mns: Vec<QuorumMasternodeListItem>,
     pub mn_list: Vec<QuorumMasternodeListItem>,
     pub deleted_quorums: Vec<QuorumItemDeleted>,
     pub new_quorums: Vec<QuorumMinableCommitments>,
-    #[serde(rename = "merkleRootMNList", with = "hex")]
+    #[serde(rename = "merkleRootMNList", with = "serde_hex")]
     pub merkle_root_mn_list: Vec<u8>,
-    #[serde(rename = "merkleRootQuorums", with = "hex")]
+    #[serde(rename = "merkleRootQuorums", with = "serde_hex")]
     pub merkle_root_quorums: Vec<u8>,
 }
 
@@ -2895,7 +3671,7 @@ pub struct DMNStateDiffIntermediate {
     pub platform_http_port: Option<u32>,
     #[serde(default)]
     pub payout_address: Option<String>,
-    #[serde(default, deserialize_with = "deserialize_hex_opt")]
+    #[serde(default, with = "serde_hex::opt")]
     pub pub_key_operator: Option<Vec<u8>>,
 }
 
@@ -2913,6 +3689,80 @@ pub struct MasternodeListDiff {
     pub updated_mns: Vec<(ProTxHash, DMNStateDiff)>,
 }
 
+// Encoded by hand since the hashes in `updated_mns` need serde compatibility but
+// `DMNStateDiff` is not `Serialize`.
+impl Encode for MasternodeListDiff {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.base_height.encode(encoder)?;
+        self.block_height.encode(encoder)?;
+        self.added_mns.encode(encoder)?;
+        Compat(&self.removed_mns).encode(encoder)?;
+        (self.updated_mns.len() as u64).encode(encoder)?;
+        for (pro_tx_hash, state_diff) in &self.updated_mns {
+            Compat(pro_tx_hash).encode(encoder)?;
+            state_diff.encode(encoder)?;
+        }
+        Ok(())
+    }
+}
+
+impl Decode for MasternodeListDiff {
+    fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let base_height = u32::decode(decoder)?;
+        let block_height = u32::decode(decoder)?;
+        let added_mns = Vec::decode(decoder)?;
+        let Compat(removed_mns) = Compat::decode(decoder)?;
+        let len = u64::decode(decoder)?;
+        let mut updated_mns = Vec::new();
+        for _ in 0..len {
+            let Compat(pro_tx_hash) = Compat::decode(decoder)?;
+            updated_mns.push((pro_tx_hash, DMNStateDiff::decode(decoder)?));
+        }
+        Ok(MasternodeListDiff {
+            base_height,
+            block_height,
+            added_mns,
+            removed_mns,
+            updated_mns,
+        })
+    }
+}
+
+impl MasternodeListDiff {
+    /// Summarizes the changes in this diff, e.g. for alerting on PoSe bans or
+    /// service and key changes.
+    pub fn summarize(&self) -> ListDiffSummary {
+        let updated_where = |changed: fn(&DMNStateDiff) -> bool| {
+            self.updated_mns
+                .iter()
+                .filter(|(_, state_diff)| changed(state_diff))
+                .map(|(pro_tx_hash, _)| *pro_tx_hash)
+                .collect()
+        };
+
+        ListDiffSummary {
+            added: self.added_mns.len(),
+            removed: self.removed_mns.len(),
+            updated: self.updated_mns.len(),
+            service_changed: updated_where(|s| s.service.is_some()),
+            pose_ban_height_changed: updated_where(|s| s.pose_ban_height.is_some()),
+            pub_key_operator_changed: updated_where(|s| s.pub_key_operator.is_some()),
+        }
+    }
+}
+
+/// Counts of the changes in a [MasternodeListDiff], and which updated
+/// masternodes had their service, PoSe ban height or operator key changed.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct ListDiffSummary {
+    pub added: usize,
+    pub removed: usize,
+    pub updated: usize,
+    pub service_changed: Vec<ProTxHash>,
+    pub pose_ban_height_changed: Vec<ProTxHash>,
+    pub pub_key_operator_changed: Vec<ProTxHash>,
+}
+
 #[derive(Clone, PartialEq, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct MasternodeListDiffIntermediate {
@@ -2972,9 +3822,44 @@ pub struct SelectQuorumResult {
 
 #[derive(Deserialize)]
 #[serde(untagged)]
-enum IntegerOrString<'a> {
+enum IntegerOrString {
     Integer(u32),
-    String(&'a str),
+    String(String),
+}
+
+/// Enums Core has returned both as their integer value and as their name,
+/// depending on the version.
+trait FromIntegerOrString: Sized {
+    fn from_integer(value: u32) -> Option<Self>;
+    fn from_name(name: &str) -> Option<Self>;
+}
+
+impl FromIntegerOrString for QuorumType {
+    fn from_integer(value: u32) -> Option<Self> {
+        Some(value.into())
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Some(name.into())
+    }
+}
+
+impl FromIntegerOrString for MasternodeType {
+    fn from_integer(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(MasternodeType::Regular),
+            1 => Some(MasternodeType::Evo),
+            _ => None,
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Regular" => Some(MasternodeType::Regular),
+            "Evo" | "HighPerformance" => Some(MasternodeType::Evo),
+            _ => None,
+        }
+    }
 }
 
 // --------------------------- ProTx -------------------------------
@@ -3010,7 +3895,7 @@ pub struct ProTxInfo {
     pub mn_type: Option<String>,
     #[serde(rename = "proTxHash")]
     pub pro_tx_hash: ProTxHash,
-    #[serde(with = "hex")]
+    #[serde(with = "serde_hex")]
     pub collateral_hash: Vec<u8>,
     pub collateral_index: u32,
     #[serde_as(as = "Bytes")]
@@ -3023,6 +3908,13 @@ pub struct ProTxInfo {
     pub meta_info: MetaInfo,
 }
 
+impl ProTxInfo {
+    /// The operator BLS public key of the current masternode state.
+    pub fn operator_pubkey(&self) -> Result<BLSPublicKey, encode::Error> {
+        self.state.operator_pubkey()
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ProTxList {
@@ -3030,6 +3922,24 @@ pub enum ProTxList {
     Info(Vec<ProTxInfo>),
 }
 
+impl ProTxList {
+    /// The detailed entries, if this is a detailed list.
+    pub fn as_info(&self) -> Option<&[ProTxInfo]> {
+        match self {
+            ProTxList::Info(info) => Some(info),
+            ProTxList::Hex(_) => None,
+        }
+    }
+
+    /// The provider transaction hashes, if this is not a detailed list.
+    pub fn as_hashes(&self) -> Option<&[ProTxHash]> {
+        match self {
+            ProTxList::Hex(hashes) => Some(hashes),
+            ProTxList::Info(_) => None,
+        }
+    }
+}
+
 #[serde_as]
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -3050,6 +3960,30 @@ pub enum ProTxRevokeReason {
     NotRecognised = 4,
 }
 
+impl From<u32> for ProTxRevokeReason {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => ProTxRevokeReason::NotSpecified,
+            1 => ProTxRevokeReason::TerminationOfService,
+            2 => ProTxRevokeReason::CompromisedKeys,
+            3 => ProTxRevokeReason::ChangeOfKeys,
+            _ => ProTxRevokeReason::NotRecognised,
+        }
+    }
+}
+
+/// Converts raw BLS public key bytes into a [BLSPublicKey], checking they are 48 bytes long.
+fn bls_public_key(bytes: &[u8]) -> Result<BLSPublicKey, encode::Error> {
+    let invalid_size = || encode::Error::InvalidVectorSize {
+        expected: 48,
+        actual: bytes.len(),
+    };
+    if bytes.len() != 48 {
+        return Err(invalid_size());
+    }
+    BLSPublicKey::try_from(bytes).map_err(|_| invalid_size())
+}
+
 // Custom deserializer functions.
 
 #[derive(Debug)]
@@ -3069,47 +4003,346 @@ impl From<FromHexError> for HexError {
     }
 }
 
-fn deserialize_hex_opt<'de, D>(deserializer: D) -> Result<Option<Vec<u8>>, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    match String::deserialize(deserializer) {
-        Ok(s) => match hex::decode(s) {
-            Ok(v) => Ok(Some(v)),
-            Err(err) => Err(D::Error::custom(HexError::from(err))),
-        },
-        Err(e) => Err(e),
+/// (De)serialization of byte fields as the hex strings Dash Core uses on the wire.
+///
+/// Use `#[serde(with = "serde_hex")]` for `Vec<u8>` fields and
+/// `#[serde(default, with = "serde_hex::opt")]` for `Option<Vec<u8>>` fields,
+/// which also accepts a missing field or `null`. Do not use `serde_with::Bytes`
+/// for hex fields: it keeps the raw characters of the string instead of
+/// decoding them.
+mod serde_hex {
+    use serde::de::Error as SerdeError;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::HexError;
+
+    pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&hex::encode(bytes))
     }
-}
 
-fn deserialize_hex_to_address_optional<'de, D>(
-    deserializer: D,
-) -> Result<Option<[u8; 20]>, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    match String::deserialize(deserializer) {
-        Ok(s) => match hex::decode(s) {
-            Ok(v) => match v.clone().try_into() {
-                Ok(array) => Ok(Some(array)),
-                Err(_) => Err(D::Error::custom(ArrayConversionError(v))),
-            },
-            Err(err) => Err(D::Error::custom(HexError::from(err))),
-        },
-        Err(e) => Err(e),
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        hex::decode(s).map_err(|e| D::Error::custom(HexError::from(e)))
     }
-}
 
-#[derive(Debug)]
-pub struct CustomAddressError(address::Error);
+    pub mod opt {
+        use serde::de::Error as SerdeError;
+        use serde::{Deserialize, Deserializer, Serializer};
 
-impl std::fmt::Display for CustomAddressError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "Failed to deserialize address: {}", self.0)
+        use crate::HexError;
+
+        pub fn serialize<S>(bytes: &Option<Vec<u8>>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match bytes {
+                Some(bytes) => super::serialize(bytes, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Vec<u8>>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            match Option::<String>::deserialize(deserializer)? {
+                Some(s) => {
+                    hex::decode(s).map(Some).map_err(|e| D::Error::custom(HexError::from(e)))
+                }
+                None => Ok(None),
+            }
+        }
     }
 }
 
-impl Error for CustomAddressError {}
+/// (De)serialization of DASH-denominated amounts.
+///
+/// This is a drop-in replacement for `dashcore::amount::serde::as_btc` that
+/// parses amounts from their decimal representation with
+/// [`Amount::from_str_in`](dashcore::Amount::from_str_in) instead of scaling an
+/// `f64`, so the number of satoshis is exact and an amount with more than 8
+/// decimals is rejected as too precise. Decimal strings are accepted as well as
+/// numbers. With the `arbitrary_precision` feature enabled the number text is
+/// used exactly as Dash Core sent it; without it the shortest representation of
+/// the parsed `f64` is used, which matches the original text for any amount of
+/// up to 15 significant digits.
+pub mod serde_amount {
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    use dashcore::amount::{Denomination, ParseAmountError};
+    use dashcore::{Amount, SignedAmount};
+    use serde::de::value::MapAccessDeserializer;
+    use serde::de::{Error as SerdeError, MapAccess, Unexpected, Visitor};
+    use serde::{Deserialize, Serializer};
+
+    const SAT_PER_DASH: i128 = 100_000_000;
+
+    /// An amount type that can be (de)serialized by [`as_btc`].
+    pub trait SerdeAmount: Sized + Copy {
+        fn to_sat_i128(self) -> i128;
+        fn from_sat_i128(sat: i128) -> Option<Self>;
+        fn from_str_dash(s: &str) -> Result<Self, ParseAmountError>;
+    }
+
+    impl SerdeAmount for Amount {
+        fn to_sat_i128(self) -> i128 {
+            self.to_sat() as i128
+        }
+
+        fn from_sat_i128(sat: i128) -> Option<Self> {
+            u64::try_from(sat).ok().map(Amount::from_sat)
+        }
+
+        fn from_str_dash(s: &str) -> Result<Self, ParseAmountError> {
+            Amount::from_str_in(s, Denomination::Dash)
+        }
+    }
+
+    impl SerdeAmount for SignedAmount {
+        fn to_sat_i128(self) -> i128 {
+            self.to_sat() as i128
+        }
+
+        fn from_sat_i128(sat: i128) -> Option<Self> {
+            i64::try_from(sat).ok().map(SignedAmount::from_sat)
+        }
+
+        fn from_str_dash(s: &str) -> Result<Self, ParseAmountError> {
+            SignedAmount::from_str_in(s, Denomination::Dash)
+        }
+    }
+
+    fn format_btc(sat: i128) -> String {
+        let sign = if sat < 0 {
+            "-"
+        } else {
+            ""
+        };
+        let abs = sat.unsigned_abs();
+        format!("{}{}.{:08}", sign, abs / SAT_PER_DASH as u128, abs % SAT_PER_DASH as u128)
+    }
+
+    struct AmountVisitor<A>(PhantomData<A>);
+
+    impl<'de, A: SerdeAmount> Visitor<'de> for AmountVisitor<A> {
+        type Value = A;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a DASH amount as a number or a decimal string")
+        }
+
+        fn visit_i64<E: SerdeError>(self, v: i64) -> Result<A, E> {
+            (v as i128)
+                .checked_mul(SAT_PER_DASH)
+                .and_then(A::from_sat_i128)
+                .ok_or_else(|| E::invalid_value(Unexpected::Signed(v), &self))
+        }
+
+        fn visit_u64<E: SerdeError>(self, v: u64) -> Result<A, E> {
+            (v as i128)
+                .checked_mul(SAT_PER_DASH)
+                .and_then(A::from_sat_i128)
+                .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self))
+        }
+
+        fn visit_f64<E: SerdeError>(self, v: f64) -> Result<A, E> {
+            if !v.is_finite() {
+                return Err(E::invalid_value(Unexpected::Float(v), &self));
+            }
+            // `Display` for `f64` prints the shortest decimal that round-trips
+            // and never uses an exponent.
+            self.visit_str(&v.to_string())
+        }
+
+        fn visit_str<E: SerdeError>(self, v: &str) -> Result<A, E> {
+            A::from_str_dash(v)
+                .map_err(|e| E::custom(format_args!("invalid amount {:?}: {}", v, e)))
+        }
+
+        // serde_json hands numbers to `deserialize_any` as a single-entry map
+        // holding the original text when `arbitrary_precision` is enabled.
+        fn visit_map<M: MapAccess<'de>>(self, map: M) -> Result<A, M::Error> {
+            let number = serde_json::Number::deserialize(MapAccessDeserializer::new(map))?;
+            let text = number.to_string();
+            // `from_str_in` takes no exponent, which Dash Core never emits.
+            match number.as_f64() {
+                Some(v) if text.contains(|c| c == 'e' || c == 'E') => self.visit_f64(v),
+                _ => self.visit_str(&text),
+            }
+        }
+    }
+
+    pub mod as_btc {
+        use std::marker::PhantomData;
+
+        use serde::{Deserializer, Serializer};
+
+        use super::{AmountVisitor, SerdeAmount};
+
+        pub fn serialize<A: SerdeAmount, S: Serializer>(
+            amount: &A,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            super::serialize_btc(amount.to_sat_i128(), serializer)
+        }
+
+        pub fn deserialize<'de, A: SerdeAmount, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<A, D::Error> {
+            deserializer.deserialize_any(AmountVisitor(PhantomData))
+        }
+
+        pub mod opt {
+            use std::fmt;
+            use std::marker::PhantomData;
+
+            use serde::de::{Error as SerdeError, Visitor};
+            use serde::{Deserializer, Serializer};
+
+            use super::super::SerdeAmount;
+
+            pub fn serialize<A: SerdeAmount, S: Serializer>(
+                amount: &Option<A>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                match amount {
+                    Some(amount) => super::serialize(amount, serializer),
+                    None => serializer.serialize_none(),
+                }
+            }
+
+            pub fn deserialize<'de, A: SerdeAmount, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Option<A>, D::Error> {
+                struct OptVisitor<A>(PhantomData<A>);
+
+                impl<'de, A: SerdeAmount> Visitor<'de> for OptVisitor<A> {
+                    type Value = Option<A>;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                        formatter.write_str("an optional DASH amount")
+                    }
+
+                    fn visit_none<E: SerdeError>(self) -> Result<Option<A>, E> {
+                        Ok(None)
+                    }
+
+                    fn visit_unit<E: SerdeError>(self) -> Result<Option<A>, E> {
+                        Ok(None)
+                    }
+
+                    fn visit_some<D: Deserializer<'de>>(
+                        self,
+                        deserializer: D,
+                    ) -> Result<Option<A>, D::Error> {
+                        super::deserialize(deserializer).map(Some)
+                    }
+                }
+
+                deserializer.deserialize_option(OptVisitor(PhantomData))
+            }
+        }
+    }
+
+    fn serialize_btc<S: Serializer>(sat: i128, serializer: S) -> Result<S::Ok, S::Error> {
+        // Going through the decimal text gives the correctly rounded `f64`.
+        let btc: f64 = format_btc(sat).parse().map_err(serde::ser::Error::custom)?;
+        serializer.serialize_f64(btc)
+    }
+}
+
+/// (De)serialization of the compact `bits` difficulty target.
+///
+/// Serializes as the 8 character hex string Dash Core uses and also accepts the
+/// target as a plain number.
+mod serde_compact_target {
+    use std::fmt;
+
+    use dashcore::CompactTarget;
+    use serde::de::{Error as SerdeError, Unexpected, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S>(bits: &CompactTarget, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&format!("{:08x}", bits.to_consensus()))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<CompactTarget, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct CompactTargetVisitor;
+
+        impl<'de> Visitor<'de> for CompactTargetVisitor {
+            type Value = CompactTarget;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a compact target as a hex string or a number")
+            }
+
+            fn visit_u64<E: SerdeError>(self, v: u64) -> Result<CompactTarget, E> {
+                u32::try_from(v)
+                    .map(CompactTarget::from_consensus)
+                    .map_err(|_| E::invalid_value(Unexpected::Unsigned(v), &self))
+            }
+
+            fn visit_i64<E: SerdeError>(self, v: i64) -> Result<CompactTarget, E> {
+                u32::try_from(v)
+                    .map(CompactTarget::from_consensus)
+                    .map_err(|_| E::invalid_value(Unexpected::Signed(v), &self))
+            }
+
+            fn visit_str<E: SerdeError>(self, v: &str) -> Result<CompactTarget, E> {
+                if v.len() != 8 {
+                    return Err(E::invalid_length(v.len(), &self));
+                }
+                u32::from_str_radix(v, 16)
+                    .map(CompactTarget::from_consensus)
+                    .map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_any(CompactTargetVisitor)
+    }
+}
+
+fn deserialize_hex_to_address_optional<'de, D>(
+    deserializer: D,
+) -> Result<Option<[u8; 20]>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match String::deserialize(deserializer) {
+        Ok(s) => match hex::decode(s) {
+            Ok(v) => match v.clone().try_into() {
+                Ok(array) => Ok(Some(array)),
+                Err(_) => Err(D::Error::custom(ArrayConversionError(v))),
+            },
+            Err(err) => Err(D::Error::custom(HexError::from(err))),
+        },
+        Err(e) => Err(e),
+    }
+}
+
+#[derive(Debug)]
+pub struct CustomAddressError(address::Error);
+
+impl std::fmt::Display for CustomAddressError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Failed to deserialize address: {}", self.0)
+    }
+}
+
+impl Error for CustomAddressError {}
 
 impl From<address::Error> for CustomAddressError {
     fn from(err: address::Error) -> CustomAddressError {
@@ -3167,22 +4400,26 @@ where
     }
 }
 
-/// deserialize_outpoint deserializes a hex-encoded outpoint
+/// deserialize_outpoint deserializes an outpoint given as `<txid>-<vout>`
 fn deserialize_outpoint<'de, D>(deserializer: D) -> Result<dashcore::OutPoint, D::Error>
 where
     D: Deserializer<'de>,
 {
     let str_sequence = String::deserialize(deserializer)?;
-    let str_array: Vec<String> = str_sequence.split('-').map(|item| item.to_owned()).collect();
+    let (txid, vout) = str_sequence
+        .split_once('-')
+        .ok_or_else(|| de::Error::custom(format!("invalid outpoint {}", str_sequence)))?;
 
-    let txid: dashcore::Txid = dashcore::Txid::from_hex(&str_array[0]).unwrap();
-    let vout: u32 = str_array[1].parse().unwrap();
+    let txid = dashcore::Txid::from_hex(txid)
+        .map_err(|e| de::Error::custom(format!("invalid outpoint txid {}: {}", txid, e)))?;
+    let vout = vout
+        .parse()
+        .map_err(|e| de::Error::custom(format!("invalid outpoint index {}: {}", vout, e)))?;
 
-    let outpoint = dashcore::OutPoint {
+    Ok(dashcore::OutPoint {
         txid,
         vout,
-    };
-    Ok(outpoint)
+    })
 }
 
 /// deserialize_mn_state deserializes a masternode state
@@ -3205,30 +4442,28 @@ where
     })
 }
 
-/// deserialize_quorum_type deserializes a quorum type
-fn deserialize_quorum_type<'de, D>(deserializer: D) -> Result<QuorumType, D::Error>
+/// Deserializes an enum given either as its integer value or as its name.
+fn deserialize_integer_or_string<'de, D, T>(deserializer: D) -> Result<T, D::Error>
 where
     D: Deserializer<'de>,
+    T: FromIntegerOrString,
 {
     match IntegerOrString::deserialize(deserializer)? {
-        IntegerOrString::String(s) => {
-            let qt: QuorumType = s.into();
-            Ok(qt)
-        }
-        IntegerOrString::Integer(n) => {
-            let qt: QuorumType = n.into();
-            Ok(qt)
-        }
+        IntegerOrString::Integer(n) => T::from_integer(n).ok_or_else(|| {
+            de::Error::invalid_value(de::Unexpected::Unsigned(n.into()), &"a known type")
+        }),
+        IntegerOrString::String(s) => T::from_name(&s)
+            .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Str(&s), &"a known type")),
     }
 }
 
-fn deserialize_f32<'de, D>(deserializer: D) -> Result<f32, D::Error>
+fn deserialize_f64<'de, D>(deserializer: D) -> Result<f64, D::Error>
 where
     D: Deserializer<'de>,
 {
     Ok(match Value::deserialize(deserializer)? {
         Value::String(s) => s.parse().map_err(de::Error::custom)?,
-        Value::Number(num) => num.as_f64().ok_or(de::Error::custom("Invalid number"))? as f32,
+        Value::Number(num) => num.as_f64().ok_or(de::Error::custom("Invalid number"))?,
         _ => return Err(de::Error::custom("wrong type")),
     })
 }
@@ -3257,10 +4492,23 @@ where
 
 #[cfg(test)]
 mod tests {
+    use dashcore::consensus::encode;
     use dashcore::hashes::Hash;
+    use dashcore::CompactTarget;
     use serde_json::json;
 
-    use crate::{deserialize_u32_opt, MasternodeListDiff, MnSyncStatus};
+    use crate::{
+        deserialize_integer_or_string, deserialize_outpoint, deserialize_u32_opt, serde_amount,
+        serde_compact_target, serde_hex, AddressDelta, AnalyzePsbtResult, AssetUnlockStatus,
+        AssetUnlockStatusResult, BlockStatsFields, BumpFeeOptions, CoinJoinInfoResult,
+        CoinbaseTxDetails, DashTxType, DecodePsbtResult, DecodeScriptResult, EstimateMode, FeeRate,
+        GetAddressUtxosChainInfoResult, GetGovernanceInfoResult, GetRawTransactionResult,
+        GetRawTransactionResultVin, GetRawTransactionResultVout, GetSpecialTxesResult,
+        GetTransactionResult, GovernanceObject, ImportDescriptorRequest, ImportMultiRescanSince,
+        ListDescriptorsResult, ListTransactionResult, Masternode, MasternodeListDiff,
+        MasternodeType, MnSyncStatus, ProTxList, ProTxRevokeReason, QuorumInfoResult,
+        QuorumListResult, QuorumType, ScanTxOutResult, ServiceFlags, SoftforkInfo, BLS,
+    };
 
     #[test]
     fn test_deserialize_u32_opt() {
@@ -3278,6 +4526,251 @@ mod tests {
         assert_eq!(result.field, None);
     }
 
+    #[test]
+    fn test_serde_hex() {
+        #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+        struct Test {
+            #[serde(with = "serde_hex")]
+            pub bytes: Vec<u8>,
+            #[serde(default, with = "serde_hex::opt")]
+            pub opt_bytes: Option<Vec<u8>>,
+        }
+
+        let result: Test =
+            serde_json::from_value(json!({"bytes": "00ff1A", "opt_bytes": "beef"})).unwrap();
+        assert_eq!(result.bytes, vec![0x00, 0xff, 0x1a]);
+        assert_eq!(result.opt_bytes, Some(vec![0xbe, 0xef]));
+        assert_eq!(
+            serde_json::to_value(&result).unwrap(),
+            json!({"bytes": "00ff1a", "opt_bytes": "beef"})
+        );
+
+        let result: Test = serde_json::from_value(json!({"bytes": ""})).unwrap();
+        assert_eq!(result.bytes, Vec::<u8>::new());
+        assert_eq!(result.opt_bytes, None);
+        let result: Test = serde_json::from_value(json!({"bytes": "", "opt_bytes": null})).unwrap();
+        assert_eq!(result.opt_bytes, None);
+        assert_eq!(serde_json::to_value(&result).unwrap(), json!({"bytes": "", "opt_bytes": null}));
+
+        assert!(serde_json::from_value::<Test>(json!({"bytes": "xyz"})).is_err());
+        assert!(serde_json::from_value::<Test>(json!({"bytes": [0, 255]})).is_err());
+    }
+
+    #[test]
+    fn deserialize_bls_as_hex() {
+        let json_value = json!({
+            "secret": "52f35cd3d977a505485f2474e7e71ef3f60f859603d72ad6b0fa7f7bd163e144",
+            "public": "885d01d746c3e4d2093b0a6ab3c1e9dcb6cbd2ee9fd7e1dc97b9dc9bb91b3f6ea1bc1780f7f3e0e7f73162b73f5ec96a"
+        });
+
+        let result: BLS = serde_json::from_value(json_value).expect("expected to deserialize json");
+        assert_eq!(32, result.secret.len());
+        assert_eq!(48, result.public.len());
+        assert_eq!(0x52, result.secret[0]);
+    }
+
+    #[test]
+    fn deserialize_address_utxos_chain_info() {
+        let json_value = json!({
+            "utxos": [{
+                "address": "yNqYnF9sHURjwRmhZMLFGQ3WjC5DZNJMUi",
+                "txid": "0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9",
+                "outputIndex": 1,
+                "script": "76a914151df6fce8d5a72ee2b6a8d6bd34ce2dc9f5d81388ac",
+                "satoshis": 150000000,
+                "height": 1200
+            }],
+            "hash": "000000b4c2e8d6ec3bf5a8e212aa9ff3b1bb3ce2cd4cbea4d667432e6ae29633",
+            "height": 1300
+        });
+
+        let result: GetAddressUtxosChainInfoResult =
+            serde_json::from_value(json_value).expect("expected to deserialize json");
+        assert_eq!(1300, result.height);
+        assert_eq!(1, result.utxos[0].output_index);
+        assert_eq!(150_000_000, result.utxos[0].satoshis.to_sat());
+        assert!(result.utxos[0].script.is_p2pkh());
+    }
+
+    #[test]
+    fn deserialize_address_deltas() {
+        let deltas: Vec<AddressDelta> = serde_json::from_value(json!([
+            {
+                "satoshis": 150000000,
+                "txid": "0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9",
+                "index": 1,
+                "blockindex": 2,
+                "height": 1200,
+                "address": "yNqYnF9sHURjwRmhZMLFGQ3WjC5DZNJMUi"
+            },
+            {
+                "satoshis": -150000000,
+                "txid": "f9e8d7c6b5a4938271605f4e3d2c1b0af9e8d7c6b5a4938271605f4e3d2c1b0a",
+                "index": 0,
+                "blockindex": 1,
+                "height": 1300,
+                "address": "yNqYnF9sHURjwRmhZMLFGQ3WjC5DZNJMUi"
+            }
+        ]))
+        .expect("expected to deserialize json");
+        assert_eq!(150_000_000, deltas[0].satoshis.to_sat());
+        assert_eq!(-150_000_000, deltas[1].satoshis.to_sat());
+    }
+
+    #[test]
+    fn scan_tx_out_by_descriptor() {
+        let utxo = |desc: &str, amount: f64| {
+            json!({
+                "txid": "0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9",
+                "vout": 0,
+                "scriptPubKey": "76a914151df6fce8d5a72ee2b6a8d6bd34ce2dc9f5d81388ac",
+                "desc": desc,
+                "amount": amount,
+                "height": 100
+            })
+        };
+        let result: ScanTxOutResult = serde_json::from_value(json!({
+            "success": true,
+            "unspents": [utxo("addr(a)", 1.5), utxo("addr(b)", 2.0), utxo("addr(a)", 0.25)],
+            "total_amount": 3.75
+        }))
+        .unwrap();
+
+        let totals = result.by_descriptor();
+        assert_eq!(2, totals.len());
+        assert_eq!(175_000_000, totals["addr(a)"].to_sat());
+        assert_eq!(200_000_000, totals["addr(b)"].to_sat());
+    }
+
+    #[test]
+    fn compact_target_serde() {
+        #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+        struct Test {
+            #[serde(with = "serde_compact_target")]
+            bits: CompactTarget,
+        }
+
+        let result: Test = serde_json::from_value(json!({"bits": "1e0ffff0"})).unwrap();
+        assert_eq!(0x1e0ffff0, result.bits.to_consensus());
+        assert_eq!(json!({"bits": "1e0ffff0"}), serde_json::to_value(&result).unwrap());
+
+        let result: Test = serde_json::from_value(json!({"bits": 0x207fffff})).unwrap();
+        assert_eq!(0x207fffff, result.bits.to_consensus());
+
+        assert!(serde_json::from_value::<Test>(json!({"bits": "1e0ffff"})).is_err());
+        assert!(serde_json::from_value::<Test>(json!({"bits": "xyz0fff0"})).is_err());
+    }
+
+    #[test]
+    fn softfork_active_at_height() {
+        let buried: SoftforkInfo =
+            serde_json::from_value(json!({"type": "buried", "active": false, "height": 500}))
+                .unwrap();
+        assert!(!buried.is_active_at(499));
+        assert!(buried.is_active_at(500));
+
+        let started: SoftforkInfo = serde_json::from_value(json!({
+            "type": "bip9",
+            "active": false,
+            "bip9": {"status": "started", "start_time": 0, "timeout": 999999999999u64, "since": 144}
+        }))
+        .unwrap();
+        assert!(!started.is_active_at(1000));
+    }
+
+    #[test]
+    fn fee_rate_for_size() {
+        use dashcore::Amount;
+
+        let rate = FeeRate::from_per_kb(Amount::from_sat(1000));
+        assert_eq!(Amount::from_sat(226), rate.fee_for_size(226));
+        let rate = FeeRate::from_per_kb(Amount::from_sat(1));
+        assert_eq!(Amount::from_sat(1), rate.fee_for_size(226));
+        assert_eq!(Amount::ZERO, rate.fee_for_size(0));
+        assert_eq!(Amount::ZERO, FeeRate::from_per_kb(Amount::ZERO).fee_for_size(226));
+    }
+
+    #[test]
+    fn dash_tx_type_from_u32() {
+        assert_eq!(DashTxType::Classic, DashTxType::from(0));
+        assert_eq!(DashTxType::CbTx, DashTxType::from(5));
+        assert_eq!(DashTxType::AssetUnlock, DashTxType::from(9));
+        assert_eq!(DashTxType::Unknown, DashTxType::from(42));
+    }
+
+    #[test]
+    fn asset_unlock_status_order() {
+        let result: Vec<AssetUnlockStatusResult> = serde_json::from_value(json!([
+            {"index": 0, "status": "chainlocked"},
+            {"index": 1, "status": "mempooled"}
+        ]))
+        .unwrap();
+        assert_eq!(AssetUnlockStatus::Chainlocked, result[0].status);
+        assert!(result[0].status > AssetUnlockStatus::Mined);
+        assert!(result[1].status < AssetUnlockStatus::Mined);
+        assert!(AssetUnlockStatus::Unknown < AssetUnlockStatus::Mempooled);
+    }
+
+    #[test]
+    fn quorum_info_typed_keys() {
+        let pubkey = "885d01d746c3e4d2093b0a6ab3c1e9dcb6cbd2ee9fd7e1dc97b9dc9bb91b3f6ea1bc1780f7f3e0e7f73162b73f5ec96a";
+        let json_value = json!({
+            "height": 264,
+            "type": "llmq_test",
+            "quorumHash": "000000b4c2e8d6ec3bf5a8e212aa9ff3b1bb3ce2cd4cbea4d667432e6ae29633",
+            "quorumIndex": 0,
+            "minedBlock": "00000065cf1ab8ba90b6c1c86b0f3b6ec49b3a1ec90e8c84874290d1dccd7b58",
+            "members": [
+                {
+                    "proTxHash": "6a5a7b4e6c4b0eb1abf6a9b7e7bb1b44937b6f0e7e5b8b4d0c6d1f1f1f1f1f1f",
+                    "pubKeyOperator": pubkey,
+                    "valid": true,
+                    "pubKeyShare": pubkey
+                },
+                {
+                    "proTxHash": "7a5a7b4e6c4b0eb1abf6a9b7e7bb1b44937b6f0e7e5b8b4d0c6d1f1f1f1f1f1f",
+                    "pubKeyOperator": pubkey,
+                    "valid": false
+                }
+            ],
+            "quorumPublicKey": pubkey,
+            "secretKeyShare": "52f35cd3d977a505485f2474e7e71ef3f60f859603d72ad6b0fa7f7bd163e1"
+        });
+
+        let result: QuorumInfoResult =
+            serde_json::from_value(json_value).expect("expected to deserialize json");
+        assert!(result.quorum_public_key().is_ok());
+        assert!(matches!(result.members[0].pub_key_share(), Some(Ok(_))));
+        assert!(result.members[1].pub_key_share().is_none());
+        assert!(matches!(
+            result.secret_key_share(),
+            Some(Err(encode::Error::InvalidVectorSize {
+                expected: 32,
+                actual: 31
+            }))
+        ));
+    }
+
+    #[test]
+    fn quorum_type_serde_roundtrip() {
+        let qt: QuorumType = serde_json::from_value(json!("llmq_test")).unwrap();
+        assert_eq!(qt, QuorumType::LlmqTest);
+        let qt: QuorumType = serde_json::from_value(json!(100)).unwrap();
+        assert_eq!(qt, QuorumType::LlmqTest);
+
+        let serialized = serde_json::to_value(QuorumType::Llmq50_60).unwrap();
+        assert_eq!(serialized, json!(1));
+        let qt: QuorumType = serde_json::from_value(serialized).unwrap();
+        assert_eq!(qt, QuorumType::Llmq50_60);
+
+        let list: QuorumListResult<Vec<u32>> =
+            serde_json::from_value(json!({"llmq_test": [1, 2], "llmq_50_60": []})).unwrap();
+        let list: QuorumListResult<Vec<u32>> =
+            serde_json::from_value(serde_json::to_value(&list).unwrap()).unwrap();
+        assert_eq!(list.quorums_by_type.get(&QuorumType::LlmqTest), Some(&vec![1, 2]));
+        assert_eq!(list.quorums_by_type.get(&QuorumType::Llmq50_60), Some(&vec![]));
+    }
+
     // #[test]
     // fn deserialize_quorum_listextended() {
     //     let json_list = r#"{
@@ -3322,6 +4815,7 @@ mod tests {
                   "collateralAddress": "yNqYnF9sHURjwRmhZMLFGQ3WjC5DZNJMUi",
                   "operatorReward": 0,
                   "state": {
+                    "version": 1,
                     "service": "194.135.88.228:6667",
                     "registeredHeight": 850310,
                     "lastPaidHeight": 0,
@@ -3344,6 +4838,7 @@ mod tests {
                   "collateralAddress": "yNqYnF9sHURjwRmhZMLFGQ3WjC5DZNJMUi",
                   "operatorReward": 0,
                   "state": {
+                    "version": 2,
                     "service": "194.135.88.227:6666",
                     "registeredHeight": 850319,
                     "lastPaidHeight": 0,
@@ -3412,8 +4907,28 @@ mod tests {
         let result: MasternodeListDiff =
             serde_json::from_str(&json).expect("expected to deserialize json");
         println!("{:#?}", result);
+
+        let config = bincode::config::standard();
+        let encoded = bincode::encode_to_vec(&result, config).expect("expected to encode");
+        let (decoded, _): (MasternodeListDiff, usize) =
+            bincode::decode_from_slice(&encoded, config).expect("expected to decode");
+        assert_eq!(result, decoded);
         assert_eq!(32, result.added_mns[0].pro_tx_hash.as_byte_array().len());
 
+        let summary = result.summarize();
+        assert_eq!((3, 3, 2), (summary.added, summary.removed, summary.updated));
+        assert!(summary.service_changed.is_empty());
+        assert_eq!(vec![result.updated_mns[0].0], summary.pose_ban_height_changed);
+        assert!(summary.pub_key_operator_changed.is_empty());
+        assert_eq!(ProTxRevokeReason::NotSpecified, result.added_mns[0].state.revocation_reason());
+        assert!(result.added_mns[0].state.operator_pubkey().is_ok());
+        assert!(result.added_mns[0].state.is_legacy_bls());
+        assert!(!result.added_mns[1].state.is_legacy_bls());
+        assert!(!result.added_mns[2].state.is_legacy_bls());
+        let mut state = result.added_mns[0].state.clone();
+        state.pub_key_operator.pop();
+        assert!(state.operator_pubkey().is_err());
+
         assert_eq!(
             "8ed3f0c208efbcfc815cbfb94490dc68cf2e29d44dd9f8a91e20e06057aa110d7062c8ab7ccc85a9ff0c88760157f563".to_string(),
             hex::encode(result.added_mns[0].state.pub_key_operator.clone()),
@@ -3437,4 +4952,620 @@ mod tests {
 
         println!("{:#?}", result);
     }
+
+    #[test]
+    fn test_serde_amount() {
+        use dashcore::{Amount, SignedAmount};
+
+        #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+        struct Test {
+            #[serde(with = "serde_amount::as_btc")]
+            amount: Amount,
+            #[serde(with = "serde_amount::as_btc")]
+            signed: SignedAmount,
+            #[serde(default, with = "serde_amount::as_btc::opt")]
+            fee: Option<Amount>,
+        }
+
+        let result: Test =
+            serde_json::from_str(r#"{"amount": 21000000.12345678, "signed": -0.00000001}"#)
+                .unwrap();
+        assert_eq!(2_100_000_012_345_678, result.amount.to_sat());
+        assert_eq!(-1, result.signed.to_sat());
+        assert_eq!(None, result.fee);
+
+        let result: Test =
+            serde_json::from_str(r#"{"amount": "0.1", "signed": 3, "fee": 1e-8}"#).unwrap();
+        assert_eq!(10_000_000, result.amount.to_sat());
+        assert_eq!(300_000_000, result.signed.to_sat());
+        assert_eq!(Some(Amount::from_sat(1)), result.fee);
+
+        let json = serde_json::to_string(&result).unwrap();
+        assert_eq!(result, serde_json::from_str(&json).unwrap());
+
+        assert!(serde_json::from_str::<Test>(r#"{"amount": -1, "signed": 0}"#).is_err());
+        assert!(serde_json::from_str::<Test>(r#"{"amount": "1.2.3", "signed": 0}"#).is_err());
+        assert!(serde_json::from_str::<Test>(r#"{"amount": "1,5", "signed": 0}"#).is_err());
+
+        // Anything below one satoshi is rejected rather than rounded.
+        let err = serde_json::from_str::<Test>(r#"{"amount": 0.000000015, "signed": 0}"#)
+            .unwrap_err()
+            .to_string();
+        let too_precise = dashcore::amount::ParseAmountError::TooPrecise.to_string();
+        assert!(err.contains(&too_precise), "{}", err);
+        assert!(serde_json::from_str::<Test>(r#"{"amount": "0.000000015", "signed": 0}"#).is_err());
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn test_serde_amount_arbitrary_precision() {
+        use dashcore::{Amount, SignedAmount};
+
+        #[derive(Debug, Deserialize)]
+        struct Test {
+            #[serde(with = "serde_amount::as_btc")]
+            amount: Amount,
+            #[serde(with = "serde_amount::as_btc")]
+            signed: SignedAmount,
+        }
+
+        // Too many digits for an `f64` to carry to the exact satoshi.
+        let result: Test = serde_json::from_str(
+            r#"{"amount": 92233720368.54775807, "signed": -92233720368.54775807}"#,
+        )
+        .unwrap();
+        assert_eq!(9_223_372_036_854_775_807, result.amount.to_sat());
+        assert_eq!(-9_223_372_036_854_775_807, result.signed.to_sat());
+
+        let result: Test = serde_json::from_str(r#"{"amount": 1e-8, "signed": 0}"#).unwrap();
+        assert_eq!(1, result.amount.to_sat());
+
+        assert!(serde_json::from_str::<Test>(r#"{"amount": 0.000000015, "signed": 0}"#).is_err());
+    }
+
+    #[test]
+    fn protx_list_accessors() {
+        let hash = "8b2eab3413abb6e04d17d1defe2b71039ba6b6f72ea1e5dab29bb10e7b745948";
+        let list = ProTxList::Hex(vec![serde_json::from_value(json!(hash)).unwrap()]);
+        assert_eq!(1, list.as_hashes().unwrap().len());
+        assert!(list.as_info().is_none());
+
+        let list = ProTxList::Info(vec![]);
+        assert!(list.as_info().unwrap().is_empty());
+        assert!(list.as_hashes().is_none());
+    }
+
+    #[test]
+    fn deserialize_integer_or_string_types() {
+        let evo: MasternodeType = deserialize_integer_or_string(json!(1)).unwrap();
+        assert_eq!(MasternodeType::Evo, evo);
+        let evo: MasternodeType = deserialize_integer_or_string(json!("Evo")).unwrap();
+        assert_eq!(MasternodeType::Evo, evo);
+        let regular: MasternodeType = deserialize_integer_or_string(json!(0)).unwrap();
+        assert_eq!(MasternodeType::Regular, regular);
+        assert!(deserialize_integer_or_string::<_, MasternodeType>(json!(7)).is_err());
+
+        let qt: QuorumType = deserialize_integer_or_string(json!(100)).unwrap();
+        assert_eq!(QuorumType::LlmqTest, qt);
+        let qt: QuorumType = deserialize_integer_or_string(json!("llmq_test")).unwrap();
+        assert_eq!(QuorumType::LlmqTest, qt);
+    }
+
+    #[test]
+    fn deserialize_coinbase_tx_details() {
+        use dashcore::Amount;
+
+        let details: CoinbaseTxDetails = serde_json::from_value(json!({
+            "version": 3,
+            "height": 1000,
+            "merkleRootMNList": "0de1b3ac3c7c6e3cd6861fcc34e1b640656ef4fa8cbec8e5d4ff46fb836dac77",
+            "merkleRootQuorums": "c32b3ede9a1cb4b8b6a8df8b0259da7896ac3f6a4b0a1ba4ac9e1b8b301c6e9b",
+            "bestCLHeightDiff": 0,
+            "bestCLSignature": "8f5f3c1b6a2e4d9c8f5f3c1b6a2e4d9c8f5f3c1b6a2e4d9c8f5f3c1b6a2e4d9c8f5f3c1b6a2e4d9c8f5f3c1b6a2e4d9c8f5f3c1b6a2e4d9c8f5f3c1b6a2e4d9c8f5f3c1b6a2e4d9c8f5f3c1b6a2e4d9c8f5f3c1b6a2e4d9c8f5f3c1b6a2e4d9c",
+            "creditPoolBalance": 1.5
+        }))
+        .unwrap();
+        assert_eq!(Some(0), details.best_cl_height_diff);
+        assert!(details.best_cl_signature.is_some());
+        assert_eq!(Some(Amount::from_sat(150_000_000)), details.credit_pool_balance);
+
+        let details: CoinbaseTxDetails = serde_json::from_value(json!({
+            "version": 2,
+            "height": 1000,
+            "merkleRootMNList": "0de1b3ac3c7c6e3cd6861fcc34e1b640656ef4fa8cbec8e5d4ff46fb836dac77",
+            "merkleRootQuorums": "c32b3ede9a1cb4b8b6a8df8b0259da7896ac3f6a4b0a1ba4ac9e1b8b301c6e9b"
+        }))
+        .unwrap();
+        assert_eq!(None, details.best_cl_signature);
+        assert_eq!(None, details.credit_pool_balance);
+    }
+
+    #[test]
+    fn block_stats_fields_keywords() {
+        use BlockStatsFields::*;
+
+        let fields = [
+            (AverageFee, "avgfee"),
+            (AverageFeeRate, "avgfeerate"),
+            (AverageTxSize, "avgtxsize"),
+            (BlockHash, "blockhash"),
+            (FeeRatePercentiles, "feerate_percentiles"),
+            (Height, "height"),
+            (Ins, "ins"),
+            (MaxFee, "maxfee"),
+            (MaxFeeRate, "maxfeerate"),
+            (MaxTxSize, "maxtxsize"),
+            (MedianFee, "medianfee"),
+            (MedianTime, "mediantime"),
+            (MedianTxSize, "mediantxsize"),
+            (MinFee, "minfee"),
+            (MinFeeRate, "minfeerate"),
+            (MinTxSize, "mintxsize"),
+            (Outs, "outs"),
+            (Subsidy, "subsidy"),
+            (SegWitTotalSize, "swtotal_size"),
+            (SegWitTotalWeight, "swtotal_weight"),
+            (SegWitTxs, "swtxs"),
+            (Time, "time"),
+            (TotalOut, "total_out"),
+            (TotalSize, "total_size"),
+            (TotalWeight, "total_weight"),
+            (TotalFee, "totalfee"),
+            (Txs, "txs"),
+            (UtxoIncrease, "utxo_increase"),
+            (UtxoSizeIncrease, "utxo_size_inc"),
+        ];
+
+        let mut keywords = std::collections::HashSet::new();
+        for (field, keyword) in &fields {
+            // Fails to compile when a variant is added without updating this test.
+            match field {
+                AverageFee | AverageFeeRate | AverageTxSize | BlockHash | FeeRatePercentiles
+                | Height | Ins | MaxFee | MaxFeeRate | MaxTxSize | MedianFee | MedianTime
+                | MedianTxSize | MinFee | MinFeeRate | MinTxSize | Outs | Subsidy
+                | SegWitTotalSize | SegWitTotalWeight | SegWitTxs | Time | TotalOut | TotalSize
+                | TotalWeight | TotalFee | Txs | UtxoIncrease | UtxoSizeIncrease => {}
+            }
+            assert_eq!(*keyword, field.get_rpc_keyword());
+            assert!(keywords.insert(field.get_rpc_keyword()), "duplicate keyword {}", keyword);
+        }
+    }
+
+    #[test]
+    fn deserialize_outpoint_errors() {
+        let txid = "0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9";
+        let outpoint = deserialize_outpoint(json!(format!("{}-1", txid))).unwrap();
+        assert_eq!(1, outpoint.vout);
+
+        assert!(deserialize_outpoint(json!("deadbeef")).is_err());
+        assert!(deserialize_outpoint(json!(format!("{}-notanumber", txid))).is_err());
+        assert!(deserialize_outpoint(json!("nothex-1")).is_err());
+    }
+
+    #[test]
+    fn deserialize_large_value_sat() {
+        let vout: GetRawTransactionResultVout = serde_json::from_value(json!({
+            "value": 50.0,
+            "valueSat": 5000000000u64,
+            "n": 0,
+            "scriptPubKey": {
+                "asm": "OP_DUP OP_HASH160 151df6fce8d5a72ee2b6a8d6bd34ce2dc9f5d813 OP_EQUALVERIFY OP_CHECKSIG",
+                "hex": "76a914151df6fce8d5a72ee2b6a8d6bd34ce2dc9f5d81388ac",
+                "type": "pubkeyhash"
+            }
+        }))
+        .unwrap();
+        assert_eq!(5_000_000_000, vout.value_sat);
+        assert_eq!(vout.value.to_sat(), vout.value_sat);
+
+        let vin: GetRawTransactionResultVin = serde_json::from_value(json!({
+            "txid": "0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9",
+            "vout": 0,
+            "value": 50.0,
+            "valueSat": 5000000000u64,
+            "sequence": 4294967295u32
+        }))
+        .unwrap();
+        assert_eq!(Some(5_000_000_000), vin.value_sat);
+    }
+
+    #[test]
+    fn service_flags_serde() {
+        let flags: ServiceFlags = serde_json::from_value(json!("0000000000000c05")).unwrap();
+        assert!(flags.has_network());
+        assert!(flags.has_bloom());
+        assert!(flags.has_network_limited());
+        assert!(flags.has(ServiceFlags::HEADERS_COMPRESSED));
+        assert!(!flags.has_compact_filters());
+        assert_eq!("0000000000000c05", flags.as_hex());
+        assert_eq!(json!("0000000000000c05"), serde_json::to_value(flags).unwrap());
+
+        assert!(serde_json::from_value::<ServiceFlags>(json!("xyz")).is_err());
+    }
+
+    #[test]
+    fn bump_fee_options_serialize() {
+        use dashcore::Amount;
+
+        let options = BumpFeeOptions::default();
+        assert_eq!(json!({}), serde_json::to_value(&options).unwrap());
+
+        let options = BumpFeeOptions {
+            conf_target: Some(6),
+            fee_rate: Some(Amount::from_sat(2_000)),
+            replaceable: None,
+            estimate_mode: Some(EstimateMode::Economical),
+        };
+        assert_eq!(
+            json!({"conf_target": 6, "fee_rate": 0.00002, "estimate_mode": "ECONOMICAL"}),
+            serde_json::to_value(&options).unwrap()
+        );
+    }
+
+    #[test]
+    fn descriptor_import_and_list() {
+        let request = ImportDescriptorRequest {
+            desc: "pkh(tpubD6NzVbkrYhZ4XgiXtGrdW5XDAPFCL9h7we1vwNCpn8tGbBcgfVYjXyhWo4E1xkh56hjod1RhGjxbaTLV3X4FyWuejifB9jusQ46QzG87VKp/0/*)#8y2jkemh",
+            active: Some(true),
+            range: Some((0, 100)),
+            timestamp: ImportMultiRescanSince::Now,
+            ..Default::default()
+        };
+        assert_eq!(
+            json!({
+                "desc": "pkh(tpubD6NzVbkrYhZ4XgiXtGrdW5XDAPFCL9h7we1vwNCpn8tGbBcgfVYjXyhWo4E1xkh56hjod1RhGjxbaTLV3X4FyWuejifB9jusQ46QzG87VKp/0/*)#8y2jkemh",
+                "active": true,
+                "range": [0, 100],
+                "timestamp": "now"
+            }),
+            serde_json::to_value(&request).unwrap()
+        );
+
+        let result: ListDescriptorsResult = serde_json::from_value(json!({
+            "wallet_name": "w",
+            "descriptors": [
+                {
+                    "desc": "pkh(tpubD6NzVbkrYhZ4XgiXtGrdW5XDAPFCL9h7we1vwNCpn8tGbBcgfVYjXyhWo4E1xkh56hjod1RhGjxbaTLV3X4FyWuejifB9jusQ46QzG87VKp/0/*)#8y2jkemh",
+                    "timestamp": 1700000000,
+                    "active": true,
+                    "internal": false,
+                    "range": [0, 999],
+                    "next": 3
+                },
+                {
+                    "desc": "addr(yP8A3cbdxRtLRduy5mXDsBnJtMzHWs6ZzJ)#cw9l6kpl",
+                    "timestamp": 1700000000,
+                    "active": false
+                }
+            ]
+        }))
+        .unwrap();
+        assert_eq!("w", result.wallet_name);
+        assert_eq!(2, result.descriptors.len());
+        assert_eq!(ImportMultiRescanSince::Timestamp(1700000000), result.descriptors[0].timestamp);
+        assert_eq!(Some((0, 999)), result.descriptors[0].range);
+        assert_eq!(Some(3), result.descriptors[0].next);
+        assert_eq!(None, result.descriptors[1].internal);
+    }
+
+    #[test]
+    fn deserialize_governance_info() {
+        use dashcore::Amount;
+
+        let info: GetGovernanceInfoResult = serde_json::from_value(json!({
+            "governanceminquorum": 1,
+            "proposalfee": 1.00000000,
+            "superblockcycle": 24,
+            "superblockmaturitywindow": 8,
+            "lastsuperblock": 240,
+            "nextsuperblock": 264,
+            "fundingthreshold": 0,
+            "governancebudget": 0
+        }))
+        .unwrap();
+        assert_eq!(Amount::from_btc(1.0).unwrap(), info.proposal_fee);
+        assert_eq!(24, info.superblock_cycle);
+        assert_eq!(264, info.next_superblock);
+    }
+
+    #[test]
+    fn deserialize_governance_object() {
+        let object: GovernanceObject = serde_json::from_value(json!({
+            "DataHex": "7b226e616d65223a2274657374227d",
+            "DataString": "{\"end_epoch\":1700600000,\"name\":\"test\",\"payment_amount\":5,\"type\":1}",
+            "Hash": "5e8d1d1e8cf5a4cd4ce1e5f0f2d8e7d2e5ac8e1d4f7b4e6d1a3c2b5e8f7a9d0c",
+            "CollateralHash": "0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9",
+            "ObjectType": 1,
+            "CreationTime": 1700000000,
+            "AbsoluteYesCount": -1,
+            "YesCount": 2,
+            "NoCount": 3,
+            "AbstainCount": 0,
+            "fBlockchainValidity": true,
+            "IsValidReason": "",
+            "fCachedValid": true,
+            "fCachedFunding": false,
+            "fCachedDelete": false,
+            "fCachedEndorsed": false
+        }))
+        .unwrap();
+        assert_eq!(json!("test"), object.data_string["name"]);
+        assert_eq!(json!(5), object.data_string["payment_amount"]);
+        assert_eq!(1, object.object_type);
+        assert_eq!(-1, object.absolute_yes_count);
+        assert_eq!(3, object.no_count);
+    }
+
+    #[test]
+    fn deserialize_coinjoin_info() {
+        use dashcore::Amount;
+
+        let info: CoinJoinInfoResult = serde_json::from_value(json!({
+            "enabled": true,
+            "multisession": false,
+            "max_sessions": 4,
+            "max_rounds": 4,
+            "max_amount": 1000,
+            "denoms_goal": 50,
+            "denoms_hardcap": 300,
+            "queue_size": 1,
+            "running": true,
+            "sessions": [
+                {
+                    "protxhash": "3a0b5320d9d2e4d0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718",
+                    "outpoint": "0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9-1",
+                    "service": "1.2.3.4:19999",
+                    "denomination": 0.10000100,
+                    "state": "QUEUE",
+                    "entries_count": 0
+                }
+            ],
+            "keys_left": 990,
+            "warnings": ""
+        }))
+        .unwrap();
+        assert_eq!(Amount::from_btc(1000.0).unwrap(), info.max_amount);
+        assert_eq!(1, info.sessions.len());
+        assert_eq!(1, info.sessions[0].outpoint.vout);
+        assert_eq!(Amount::from_sat(10_000_100), info.sessions[0].denomination);
+    }
+
+    #[test]
+    fn deserialize_special_txes() {
+        let txid = "0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9";
+        match serde_json::from_value(json!([txid])).unwrap() {
+            GetSpecialTxesResult::Txids(txids) => assert_eq!(txid, txids[0].to_string()),
+            r => panic!("unexpected result: {:?}", r),
+        }
+
+        let hex = "03000500010000000000000000000000000000000000000000000000000000000000000000ffffffff0502f6050101ffffffff0000000000";
+        match serde_json::from_value(json!([hex])).unwrap() {
+            GetSpecialTxesResult::Hex(hexes) => assert_eq!(vec![hex.to_owned()], hexes),
+            r => panic!("unexpected result: {:?}", r),
+        }
+    }
+
+    #[test]
+    fn deserialize_analyze_psbt() {
+        use dashcore::Amount;
+
+        let result: AnalyzePsbtResult = serde_json::from_value(json!({
+            "inputs": [
+                {
+                    "has_utxo": true,
+                    "is_final": false,
+                    "missing": {
+                        "signatures": ["151df6fce8d5a72ee2b6a8d6bd34ce2dc9f5d813"]
+                    },
+                    "next": "signer"
+                }
+            ],
+            "estimated_vsize": 192,
+            "estimated_feerate": 0.00010000,
+            "fee": 0.00001920,
+            "next": "signer"
+        }))
+        .unwrap();
+        let missing = result.inputs[0].missing.as_ref().unwrap();
+        assert_eq!(vec!["151df6fce8d5a72ee2b6a8d6bd34ce2dc9f5d813".to_owned()], missing.signatures);
+        assert!(missing.pubkeys.is_empty());
+        assert_eq!(Some(Amount::from_sat(1920)), result.fee);
+        assert_eq!(Some(Amount::from_sat(10_000)), result.estimated_feerate);
+
+        let result: AnalyzePsbtResult =
+            serde_json::from_value(json!({"next": "creator", "error": "PSBT is not valid"}))
+                .unwrap();
+        assert!(result.inputs.is_empty());
+        assert_eq!(None, result.fee);
+    }
+
+    #[test]
+    fn deserialize_decoded_transaction() {
+        let tx: GetRawTransactionResult = serde_json::from_value(json!({
+            "txid": "0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9",
+            "version": 2,
+            "type": 0,
+            "size": 85,
+            "locktime": 0,
+            "vin": [],
+            "vout": []
+        }))
+        .unwrap();
+        assert!(tx.hex.is_empty());
+        assert!(!tx.instantlock);
+        assert!(!tx.chainlock);
+
+        let script: DecodeScriptResult = serde_json::from_value(json!({
+            "asm": "OP_DUP OP_HASH160 151df6fce8d5a72ee2b6a8d6bd34ce2dc9f5d813 OP_EQUALVERIFY OP_CHECKSIG",
+            "type": "pubkeyhash",
+            "reqSigs": 1,
+            "addresses": ["yNDp7n3KmA6gBQ9EM2bWwBoQT4zXBU8arE"],
+            "p2sh": "8pJwHYEXbDfgCTsY5J7EsMvdB2Ao4XRVdZ"
+        }))
+        .unwrap();
+        assert_eq!(Some(1), script.req_sigs);
+        assert_eq!(1, script.addresses.len());
+        assert!(script.p2sh.is_some());
+    }
+
+    #[test]
+    fn deserialize_decode_psbt() {
+        use dashcore::Amount;
+
+        let result: DecodePsbtResult = serde_json::from_value(json!({
+            "tx": {
+                "txid": "0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9",
+                "version": 2,
+                "type": 0,
+                "size": 85,
+                "locktime": 0,
+                "vin": [
+                    {
+                        "txid": "5e8d1d1e8cf5a4cd4ce1e5f0f2d8e7d2e5ac8e1d4f7b4e6d1a3c2b5e8f7a9d0c",
+                        "vout": 1,
+                        "scriptSig": {"asm": "", "hex": ""},
+                        "sequence": 4294967295u32
+                    }
+                ],
+                "vout": []
+            },
+            "unknown": {},
+            "inputs": [
+                {
+                    "witness_utxo": {
+                        "amount": 2.5,
+                        "scriptPubKey": {
+                            "asm": "OP_DUP OP_HASH160 151df6fce8d5a72ee2b6a8d6bd34ce2dc9f5d813 OP_EQUALVERIFY OP_CHECKSIG",
+                            "hex": "76a914151df6fce8d5a72ee2b6a8d6bd34ce2dc9f5d81388ac",
+                            "type": "pubkeyhash"
+                        }
+                    },
+                    "partial_signatures": {
+                        "03dae30a4d7870cd87b45dd53e6012f71318fdd059c1c2623b8cc73f8af287bb2d": "3045022100e85425f6d7c589972ee061413bcf08dc8c8e589ce37b217535a42af924f0e4d602205c9ba9cb14ef15513c9d946fa1c4b797883e748e8c32171bdf6166583946e35c01"
+                    },
+                    "sighash": "ALL"
+                }
+            ],
+            "outputs": [
+                {
+                    "bip32_derivs": [
+                        {
+                            "pubkey": "03dae30a4d7870cd87b45dd53e6012f71318fdd059c1c2623b8cc73f8af287bb2d",
+                            "master_fingerprint": "0c8a6a1b",
+                            "path": "m/44'/1'/0'/0/3"
+                        }
+                    ]
+                }
+            ],
+            "fee": 0.0000226
+        }))
+        .unwrap();
+        let input = &result.inputs[0];
+        assert_eq!(Amount::from_btc(2.5).unwrap(), input.witness_utxo.as_ref().unwrap().amount);
+        assert_eq!(1, input.partial_signatures.len());
+        assert_eq!(Some("ALL"), input.sighash.as_deref());
+        assert_eq!("m/44'/1'/0'/0/3", result.outputs[0].bip32_derivs[0].path.to_string());
+        assert_eq!(Some(Amount::from_sat(2260)), result.fee);
+    }
+
+    #[test]
+    fn masternode_addresses() {
+        use dashcore::Network;
+
+        let mn: Masternode = serde_json::from_value(json!({
+            "proTxHash": "0d4e453fe6fde2ebe4e4ad5bcc2bbbb4f6097ab7fcf3b31b0733831b970d2d9c",
+            "address": "127.0.0.1:19999",
+            "payee": "yNDp7n3KmA6gBQ9EM2bWwBoQT4zXBU8arE",
+            "status": "ENABLED",
+            "type": "Regular",
+            "pospenaltyscore": 0,
+            "consecutivePayments": 0,
+            "lastpaidtime": 0,
+            "lastpaidblock": 0,
+            "owneraddress": "yNqYnF9sHURjwRmhZMLFGQ3WjC5DZNJMUi",
+            "votingaddress": "yNqYnF9sHURjwRmhZMLFGQ3WjC5DZNJMUi",
+            "collateraladdress": "yLtkvxSueGSufQZQq8L9GVHch9QRqJqGkZ",
+            "pubkeyoperator": "00"
+        }))
+        .unwrap();
+        assert_eq!(
+            "yNDp7n3KmA6gBQ9EM2bWwBoQT4zXBU8arE",
+            mn.payee_address(Network::Testnet).unwrap().to_string()
+        );
+        assert_eq!(
+            mn.owner_address(Network::Testnet).unwrap(),
+            mn.voting_address(Network::Testnet).unwrap()
+        );
+        assert_eq!(
+            "yLtkvxSueGSufQZQq8L9GVHch9QRqJqGkZ",
+            mn.collateral_address(Network::Testnet).unwrap().to_string()
+        );
+        assert!(mn.payee_address(Network::Dash).is_err());
+    }
+
+    #[test]
+    fn list_transaction_result_round_trip() {
+        let tx: ListTransactionResult = serde_json::from_value(json!({
+            "address": "yNDp7n3KmA6gBQ9EM2bWwBoQT4zXBU8arE",
+            "category": "send",
+            "amount": -1.5,
+            "label": "",
+            "vout": 1,
+            "fee": -0.0000226,
+            "abandoned": false,
+            "confirmations": 3,
+            "blockhash": "000000000000001a3b7cb0b9d3ee8e4ba537c7b0eb9f250e169f2e1b3d9544b2",
+            "blockindex": 2,
+            "blocktime": 1700000000,
+            "blockheight": 900000,
+            "txid": "5b18d3c7c2aa0fd0a24574a2c0eddb1fa1d760413f2fe3c2808a7e0eb55abbb8",
+            "walletconflicts": [],
+            "time": 1700000000,
+            "timereceived": 1699999990,
+            "trusted": true
+        }))
+        .unwrap();
+        let value = serde_json::to_value(&tx).unwrap();
+        assert_eq!(tx, serde_json::from_value::<ListTransactionResult>(value).unwrap());
+    }
+
+    #[test]
+    fn deserialize_get_transaction_result() {
+        use dashcore::SignedAmount;
+
+        let tx: GetTransactionResult = serde_json::from_value(json!({
+            "amount": -1.5,
+            "fee": -0.0000226,
+            "confirmations": 0,
+            "instantlock": true,
+            "instantlock_internal": true,
+            "chainlock": false,
+            "trusted": true,
+            "txid": "5b18d3c7c2aa0fd0a24574a2c0eddb1fa1d760413f2fe3c2808a7e0eb55abbb8",
+            "walletconflicts": [],
+            "time": 1700000000,
+            "timereceived": 1700000000,
+            "details": [
+                {
+                    "address": "yNDp7n3KmA6gBQ9EM2bWwBoQT4zXBU8arE",
+                    "category": "send",
+                    "amount": -1.5,
+                    "vout": 0,
+                    "fee": -0.0000226,
+                    "abandoned": false
+                }
+            ],
+            "hex": "0100000001b8bb5ab50e7e8a80c2e32f3f4160d7a11fdbedc0a27445a2d00faac2c7d3185b0000000000ffffffff0180d1f008000000001976a914151df6fce8d5a72ee2b6a8d6bd34ce2dc9f5d81388ac00000000"
+        }))
+        .unwrap();
+        assert_eq!(SignedAmount::from_sat(-150_000_000), tx.amount);
+        assert_eq!(Some(SignedAmount::from_sat(-2260)), tx.fee);
+        assert!(tx.instantlock);
+        assert!(tx.info.blockhash.is_none());
+        assert_eq!(1, tx.details.len());
+        assert_eq!(85, tx.hex.len());
+        let transaction = tx.transaction().unwrap();
+        assert_eq!(1, transaction.input.len());
+        assert_eq!(150_000_000, transaction.output[0].value);
+    }
 }